@@ -0,0 +1,110 @@
+//! Benchmarks for rendering checkboxes.
+//!
+//! Besides timing, each benchmark reports the heap allocations made per render, counted by a
+//! wrapping global allocator, since allocations dominate the cost of rendering long lists.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::hint::black_box;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::widgets::Widget;
+use tui_checkbox::{CachedCheckbox, Checkbox};
+
+/// Counts every allocation before handing it to the system allocator.
+struct CountingAllocator;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+// SAFETY: every call is forwarded unchanged to the system allocator.
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+/// Prints the average number of allocations made by `render` over the checkboxes.
+fn report_allocations(name: &str, checkboxes: &[Checkbox], buffer: &mut Buffer) {
+    let before = ALLOCATIONS.load(Ordering::Relaxed);
+    for checkbox in checkboxes {
+        Widget::render(checkbox, buffer.area, buffer);
+    }
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed) - before;
+    println!(
+        "{name}: {:.1} allocations per render",
+        allocations as f64 / checkboxes.len() as f64
+    );
+}
+
+fn render_list(c: &mut Criterion) {
+    let labels: Vec<String> = (0..1000).map(|i| format!("Option number {i}")).collect();
+    let checkboxes: Vec<Checkbox> = labels
+        .iter()
+        .enumerate()
+        .map(|(i, label)| Checkbox::new(label.as_str(), i % 2 == 0))
+        .collect();
+    let mut buffer = Buffer::empty(Rect::new(0, 0, 40, 1));
+
+    report_allocations("render 1000 checkboxes", &checkboxes, &mut buffer);
+    c.bench_function("render 1000 checkboxes", |b| {
+        b.iter(|| {
+            for checkbox in &checkboxes {
+                Widget::render(black_box(checkbox), buffer.area, &mut buffer);
+            }
+        });
+    });
+}
+
+fn render_wrapped(c: &mut Criterion) {
+    let checkboxes = [Checkbox::new(
+        "A long label that wraps over several lines in a narrow column",
+        true,
+    )
+    .wrap_label(true)];
+    let mut buffer = Buffer::empty(Rect::new(0, 0, 20, 5));
+
+    report_allocations("render wrapped label", &checkboxes, &mut buffer);
+    c.bench_function("render wrapped label", |b| {
+        b.iter(|| Widget::render(black_box(&checkboxes[0]), buffer.area, &mut buffer));
+    });
+}
+
+fn render_wrapped_cached(c: &mut Criterion) {
+    let checkbox = Checkbox::new(
+        "A long label that wraps over several lines in a narrow column",
+        true,
+    )
+    .wrap_label(true);
+    let mut cached = CachedCheckbox::new(checkbox.clone());
+    let mut buffer = Buffer::empty(Rect::new(0, 0, 20, 5));
+
+    let mut group = c.benchmark_group("render wrapped label 1000 times");
+    group.bench_function("uncached", |b| {
+        b.iter(|| {
+            for _ in 0..1000 {
+                Widget::render(black_box(&checkbox), buffer.area, &mut buffer);
+            }
+        });
+    });
+    group.bench_function("cached", |b| {
+        b.iter(|| {
+            for _ in 0..1000 {
+                black_box(&mut cached).render(buffer.area, &mut buffer);
+            }
+        });
+    });
+    group.finish();
+}
+
+criterion_group!(benches, render_list, render_wrapped, render_wrapped_cached);
+criterion_main!(benches);
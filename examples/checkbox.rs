@@ -82,21 +82,19 @@ fn run(mut terminal: DefaultTerminal, app: &mut App) -> Result<()> {
                         ViewMode::ApiShowcase => ViewMode::Interactive,
                     };
                 }
-                KeyCode::Up | KeyCode::Char('k') => {
-                    if app.mode == ViewMode::Interactive && app.selected > 0 {
-                        app.selected -= 1;
-                    }
+                KeyCode::Up | KeyCode::Char('k')
+                    if app.mode == ViewMode::Interactive && app.selected > 0 =>
+                {
+                    app.selected -= 1;
                 }
-                KeyCode::Down | KeyCode::Char('j') => {
-                    if app.mode == ViewMode::Interactive && app.selected < app.checkboxes.len() - 1
-                    {
-                        app.selected += 1;
-                    }
+                KeyCode::Down | KeyCode::Char('j')
+                    if app.mode == ViewMode::Interactive
+                        && app.selected < app.checkboxes.len() - 1 =>
+                {
+                    app.selected += 1;
                 }
-                KeyCode::Char(' ') | KeyCode::Enter => {
-                    if app.mode == ViewMode::Interactive {
-                        app.checkboxes[app.selected] = !app.checkboxes[app.selected];
-                    }
+                KeyCode::Char(' ') | KeyCode::Enter if app.mode == ViewMode::Interactive => {
+                    app.checkboxes[app.selected] = !app.checkboxes[app.selected];
                 }
                 _ => {}
             }
@@ -229,11 +227,10 @@ fn render_basic_section(frame: &mut Frame, area: Rect, app: &App) {
     .split(inner)[1];
 
     let is_selected = app.selected == 0;
-    let checkbox = Checkbox::new("Enable notifications", app.checkboxes[0]).style(if is_selected {
-        Style::default().bg(Color::DarkGray)
-    } else {
-        Style::default()
-    });
+    let checkbox = Checkbox::new("Enable notifications", app.checkboxes[0])
+        .when(is_selected, |checkbox| {
+            checkbox.style(Style::default().bg(Color::DarkGray))
+        });
 
     frame.render_widget(checkbox, checkbox_area);
 }
@@ -260,10 +257,8 @@ fn render_styled_section(frame: &mut Frame, area: Rect, app: &App) {
     let checkbox = Checkbox::new("Info state", app.checkboxes[1])
         .checkbox_style(Style::default().fg(Color::Blue))
         .label_style(Style::default().fg(Color::Blue))
-        .style(if is_selected {
-            Style::default().bg(Color::DarkGray)
-        } else {
-            Style::default()
+        .when(is_selected, |checkbox| {
+            checkbox.style(Style::default().bg(Color::DarkGray))
         });
     frame.render_widget(checkbox, items_layout[0]);
 
@@ -272,10 +267,8 @@ fn render_styled_section(frame: &mut Frame, area: Rect, app: &App) {
     let checkbox = Checkbox::new("Warning state", app.checkboxes[2])
         .checkbox_style(Style::default().fg(Color::Yellow))
         .label_style(Style::default().fg(Color::Yellow))
-        .style(if is_selected {
-            Style::default().bg(Color::DarkGray)
-        } else {
-            Style::default()
+        .when(is_selected, |checkbox| {
+            checkbox.style(Style::default().bg(Color::DarkGray))
         });
     frame.render_widget(checkbox, items_layout[1]);
 
@@ -284,10 +277,8 @@ fn render_styled_section(frame: &mut Frame, area: Rect, app: &App) {
     let checkbox = Checkbox::new("Error state", app.checkboxes[3])
         .checkbox_style(Style::default().fg(Color::Red).add_modifier(Modifier::BOLD))
         .label_style(Style::default().fg(Color::Red))
-        .style(if is_selected {
-            Style::default().bg(Color::DarkGray)
-        } else {
-            Style::default()
+        .when(is_selected, |checkbox| {
+            checkbox.style(Style::default().bg(Color::DarkGray))
         });
     frame.render_widget(checkbox, items_layout[2]);
 }
@@ -314,10 +305,8 @@ fn render_emoji_section(frame: &mut Frame, area: Rect, app: &App) {
     let checkbox = Checkbox::new("Check mark style", app.checkboxes[4])
         .checked_symbol("✅ ")
         .unchecked_symbol("⬜ ")
-        .style(if is_selected {
-            Style::default().bg(Color::DarkGray)
-        } else {
-            Style::default()
+        .when(is_selected, |checkbox| {
+            checkbox.style(Style::default().bg(Color::DarkGray))
         });
     frame.render_widget(checkbox, items_layout[0]);
 
@@ -326,10 +315,8 @@ fn render_emoji_section(frame: &mut Frame, area: Rect, app: &App) {
     let checkbox = Checkbox::new("Circle style", app.checkboxes[5])
         .checked_symbol("● ")
         .unchecked_symbol("○ ")
-        .style(if is_selected {
-            Style::default().bg(Color::DarkGray)
-        } else {
-            Style::default()
+        .when(is_selected, |checkbox| {
+            checkbox.style(Style::default().bg(Color::DarkGray))
         });
     frame.render_widget(checkbox, items_layout[1]);
 
@@ -338,10 +325,8 @@ fn render_emoji_section(frame: &mut Frame, area: Rect, app: &App) {
     let checkbox = Checkbox::new("Diamond style", app.checkboxes[6])
         .checked_symbol("◆ ")
         .unchecked_symbol("◇ ")
-        .style(if is_selected {
-            Style::default().bg(Color::DarkGray)
-        } else {
-            Style::default()
+        .when(is_selected, |checkbox| {
+            checkbox.style(Style::default().bg(Color::DarkGray))
         });
     frame.render_widget(checkbox, items_layout[2]);
 }
@@ -369,10 +354,8 @@ fn render_custom_section(frame: &mut Frame, area: Rect, app: &App) {
     let checkbox = Checkbox::new("ASCII style", app.checkboxes[7])
         .checked_symbol(symbols::CHECKED_X)
         .unchecked_symbol(symbols::UNCHECKED_SPACE)
-        .style(if is_selected {
-            Style::default().bg(Color::DarkGray)
-        } else {
-            Style::default()
+        .when(is_selected, |checkbox| {
+            checkbox.style(Style::default().bg(Color::DarkGray))
         });
     frame.render_widget(checkbox, items_layout[0]);
 
@@ -381,10 +364,8 @@ fn render_custom_section(frame: &mut Frame, area: Rect, app: &App) {
     let checkbox = Checkbox::new("Asterisk", app.checkboxes[8])
         .checked_symbol(symbols::CHECKED_ASTERISK)
         .unchecked_symbol(symbols::UNCHECKED_SPACE)
-        .style(if is_selected {
-            Style::default().bg(Color::DarkGray)
-        } else {
-            Style::default()
+        .when(is_selected, |checkbox| {
+            checkbox.style(Style::default().bg(Color::DarkGray))
         });
     frame.render_widget(checkbox, items_layout[1]);
 
@@ -398,10 +379,8 @@ fn render_custom_section(frame: &mut Frame, area: Rect, app: &App) {
     let checkbox = Checkbox::new(label, app.checkboxes[9])
         .checked_symbol(symbols::CHECKED_PLUS)
         .unchecked_symbol(symbols::UNCHECKED_MINUS)
-        .style(if is_selected {
-            Style::default().bg(Color::DarkGray)
-        } else {
-            Style::default()
+        .when(is_selected, |checkbox| {
+            checkbox.style(Style::default().bg(Color::DarkGray))
         });
     frame.render_widget(checkbox, items_layout[2]);
 
@@ -410,10 +389,8 @@ fn render_custom_section(frame: &mut Frame, area: Rect, app: &App) {
     let checkbox = Checkbox::new("X/O style", app.checkboxes[10])
         .checked_symbol(symbols::CHECKED_PARENTHESIS_X)
         .unchecked_symbol(symbols::UNCHECKED_PARENTHESIS_O)
-        .style(if is_selected {
-            Style::default().bg(Color::DarkGray)
-        } else {
-            Style::default()
+        .when(is_selected, |checkbox| {
+            checkbox.style(Style::default().bg(Color::DarkGray))
         });
     frame.render_widget(checkbox, items_layout[3]);
 }
@@ -0,0 +1,88 @@
+use std::io;
+
+use ratatui::{
+    backend::CrosstermBackend,
+    crossterm::{
+        event::{self, Event, KeyCode, KeyEventKind},
+        execute,
+        terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
+    },
+    layout::{Constraint, Direction, Layout},
+    style::{Color, Modifier, Style},
+    widgets::{Block, Borders, Paragraph},
+    Frame, Terminal,
+};
+use tui_checkbox::{CheckboxGroup, CheckboxGroupState};
+
+const LABELS: [&str; 5] = [
+    "Enable notifications",
+    "Auto-save documents",
+    "Dark mode",
+    "Show line numbers",
+    "Check for updates",
+];
+
+fn main() -> io::Result<()> {
+    enable_raw_mode()?;
+    let mut stdout = io::stdout();
+    execute!(stdout, EnterAlternateScreen)?;
+    let backend = CrosstermBackend::new(stdout);
+    let mut terminal = Terminal::new(backend)?;
+
+    let result = run(&mut terminal);
+
+    disable_raw_mode()?;
+    execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
+    terminal.show_cursor()?;
+
+    result
+}
+
+fn run(terminal: &mut Terminal<CrosstermBackend<io::Stdout>>) -> io::Result<()> {
+    let mut state = CheckboxGroupState::new(vec![false; LABELS.len()]).with_selected(Some(0));
+    loop {
+        terminal.draw(|frame| render(frame, &mut state))?;
+
+        if let Event::Key(key) = event::read()? {
+            if key.kind != KeyEventKind::Press {
+                continue;
+            }
+            match key.code {
+                KeyCode::Char('q') | KeyCode::Esc => return Ok(()),
+                KeyCode::Up | KeyCode::Char('k') => state.previous(),
+                KeyCode::Down | KeyCode::Char('j') => state.next(),
+                KeyCode::Char(' ') | KeyCode::Enter => {
+                    state.toggle_selected();
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+fn render(frame: &mut Frame, state: &mut CheckboxGroupState) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(2), Constraint::Min(0)])
+        .split(frame.area());
+
+    let header = Paragraph::new("Checkbox Group Demo - ↑/↓ to move, Space to toggle, 'q' to quit")
+        .style(
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+        )
+        .centered();
+    frame.render_widget(header, chunks[0]);
+
+    let group = CheckboxGroup::from_labels(LABELS)
+        .block(
+            Block::default()
+                .title("Settings")
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Yellow)),
+        )
+        .highlight_style(Style::default().bg(Color::DarkGray))
+        .spacing(1);
+    frame.render_stateful_widget(group, chunks[1], state);
+}
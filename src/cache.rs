@@ -0,0 +1,193 @@
+//! A [`Checkbox`] that keeps its wrapped label between renders
+//!
+//! [`Checkbox`]: crate::Checkbox
+
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::text::{Line, Span};
+use ratatui::widgets::Widget;
+
+use crate::Checkbox;
+
+/// A [`Checkbox`] that caches its wrapped label lines across renders.
+///
+/// Wrapping a long label measures every word on every frame. A cached checkbox keeps the lines
+/// from the last render, keyed by the width they were wrapped in and a hash of the label, and
+/// reuses them while both stay the same, so large forms of wrapped labels render faster. The
+/// cache is rebuilt when the width changes and cleared whenever the checkbox is borrowed
+/// mutably with [`CachedCheckbox::checkbox_mut`]. Labels that are not wrapped are not cached.
+///
+/// The widget is rendered through a mutable reference, since rendering updates the cache.
+///
+/// # Examples
+///
+/// ```
+/// use ratatui::buffer::Buffer;
+/// use ratatui::layout::Rect;
+/// use ratatui::widgets::Widget;
+/// use tui_checkbox::{CachedCheckbox, Checkbox};
+///
+/// let mut cached = CachedCheckbox::new(
+///     Checkbox::new("A long label that wraps in a narrow column", true).wrap_label(true),
+/// );
+///
+/// let mut buffer = Buffer::empty(Rect::new(0, 0, 16, 4));
+/// for _ in 0..3 {
+///     // Only the first render wraps the label
+///     (&mut cached).render(buffer.area, &mut buffer);
+/// }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct CachedCheckbox<'a> {
+    /// The wrapped checkbox
+    checkbox: Checkbox<'a>,
+    /// The wrapped label lines of the last render
+    cache: WrapCache,
+}
+
+impl<'a> CachedCheckbox<'a> {
+    /// Wraps `checkbox` with an empty cache.
+    #[must_use]
+    pub fn new(checkbox: Checkbox<'a>) -> Self {
+        Self {
+            checkbox,
+            cache: WrapCache::default(),
+        }
+    }
+
+    /// Returns the wrapped checkbox.
+    #[must_use]
+    pub const fn checkbox(&self) -> &Checkbox<'a> {
+        &self.checkbox
+    }
+
+    /// Returns the wrapped checkbox for changes, clearing the cache.
+    pub fn checkbox_mut(&mut self) -> &mut Checkbox<'a> {
+        self.cache = WrapCache::default();
+        &mut self.checkbox
+    }
+
+    /// Returns the wrapped checkbox, dropping the cache.
+    #[must_use]
+    pub fn into_inner(self) -> Checkbox<'a> {
+        self.checkbox
+    }
+}
+
+impl<'a> From<Checkbox<'a>> for CachedCheckbox<'a> {
+    fn from(checkbox: Checkbox<'a>) -> Self {
+        Self::new(checkbox)
+    }
+}
+
+impl Widget for &mut CachedCheckbox<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        self.checkbox
+            .render_with_cache(area, buf, Some(&mut self.cache));
+    }
+}
+
+/// Wrapped label lines, keyed by the width they were wrapped in and a hash of the label.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct WrapCache {
+    /// The width and label hash the lines were wrapped for
+    key: Option<(u16, u64)>,
+    /// The wrapped lines
+    lines: Vec<Line<'static>>,
+}
+
+impl WrapCache {
+    /// Returns the lines of `label` wrapped within `max_width`, calling `wrap` only when the
+    /// label or width differ from the cached ones.
+    pub(crate) fn lines<F>(&mut self, label: &[Line<'_>], max_width: u16, wrap: F) -> Vec<Line<'_>>
+    where
+        F: FnOnce(&[Line<'_>]) -> Vec<Line<'static>>,
+    {
+        let mut hasher = DefaultHasher::new();
+        label.hash(&mut hasher);
+        let key = Some((max_width, hasher.finish()));
+        if self.key != key {
+            self.lines = wrap(label);
+            self.key = key;
+        }
+
+        // The lines borrow their text from the cache, so a hit copies no strings
+        self.lines
+            .iter()
+            .map(|line| Line {
+                spans: line
+                    .spans
+                    .iter()
+                    .map(|span| Span::styled(span.content.as_ref(), span.style))
+                    .collect(),
+                style: line.style,
+                alignment: line.alignment,
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LABEL: &str = "A long label that wraps over several lines";
+
+    fn render_uncached(checkbox: &Checkbox, area: Rect) -> Buffer {
+        let mut buffer = Buffer::empty(area);
+        Widget::render(checkbox, area, &mut buffer);
+        buffer
+    }
+
+    #[test]
+    fn cached_renders_like_checkbox() {
+        let checkbox = Checkbox::new(LABEL, true).wrap_label(true);
+        let mut cached = CachedCheckbox::new(checkbox.clone());
+        let area = Rect::new(0, 0, 16, 4);
+        let mut buffer = Buffer::empty(area);
+        (&mut cached).render(area, &mut buffer);
+        assert_eq!(buffer, render_uncached(&checkbox, area));
+    }
+
+    #[test]
+    fn cached_reuses_lines_at_same_width() {
+        let mut cached = CachedCheckbox::new(Checkbox::new(LABEL, true).wrap_label(true));
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 16, 4));
+        (&mut cached).render(buffer.area, &mut buffer);
+        let lines = cached.cache.lines.as_ptr();
+        (&mut cached).render(buffer.area, &mut buffer);
+        assert_eq!(cached.cache.lines.as_ptr(), lines);
+    }
+
+    #[test]
+    fn cached_invalidates_on_width_change() {
+        let checkbox = Checkbox::new(LABEL, true).wrap_label(true);
+        let mut cached = CachedCheckbox::new(checkbox.clone());
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 16, 4));
+        (&mut cached).render(buffer.area, &mut buffer);
+        let key = cached.cache.key;
+
+        let area = Rect::new(0, 0, 24, 4);
+        let mut buffer = Buffer::empty(area);
+        (&mut cached).render(area, &mut buffer);
+        assert_ne!(cached.cache.key, key);
+        assert_eq!(buffer, render_uncached(&checkbox, area));
+    }
+
+    #[test]
+    fn cached_checkbox_mut_clears_cache() {
+        let mut cached = CachedCheckbox::new(Checkbox::new(LABEL, true).wrap_label(true));
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 16, 4));
+        (&mut cached).render(buffer.area, &mut buffer);
+        assert!(cached.cache.key.is_some());
+
+        *cached.checkbox_mut() = Checkbox::new("Short", false).wrap_label(true);
+        assert!(cached.cache.key.is_none());
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 16, 4));
+        (&mut cached).render(buffer.area, &mut buffer);
+        assert_eq!(crate::buffer_rows(&buffer), ["☐ Short", "", "", ""]);
+    }
+}
@@ -0,0 +1,210 @@
+//! A [`Checkbox`] that cycles through any number of states
+//!
+//! [`Checkbox`]: crate::Checkbox
+
+use std::borrow::Cow;
+
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::Style;
+use ratatui::text::Line;
+use ratatui::widgets::Widget;
+
+use crate::Checkbox;
+
+/// A checkbox with an ordered list of states, each with its own value, symbol and symbol style.
+///
+/// This generalizes the checked, unchecked and indeterminate states of a [`Checkbox`] to any
+/// number of states, such as include, exclude and ignore in a filter. [`CycleBox::toggle`]
+/// advances to the next state, wrapping around from the last to the first.
+///
+/// The label and layout are drawn by a [`Checkbox`], which can be configured with
+/// [`CycleBox::checkbox`]. Its symbol is replaced by the symbol of the current state, and the
+/// style of the state is patched over its [`Checkbox::checkbox_style`].
+///
+/// # Examples
+///
+/// ```
+/// use ratatui::style::{Color, Style};
+/// use tui_checkbox::CycleBox;
+///
+/// #[derive(Debug, PartialEq)]
+/// enum Filter {
+///     Include,
+///     Exclude,
+///     Ignore,
+/// }
+///
+/// let mut filter = CycleBox::new(
+///     "Archived",
+///     [
+///         (Filter::Include, "[+]", Style::default().fg(Color::Green)),
+///         (Filter::Exclude, "[-]", Style::default().fg(Color::Red)),
+///         (Filter::Ignore, "[ ]", Style::default()),
+///     ],
+/// );
+/// filter.toggle();
+/// assert_eq!(filter.value(), Some(&Filter::Exclude));
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct CycleBox<'a, T> {
+    /// The checkbox drawing the label and the symbol of the current state
+    checkbox: Checkbox<'a>,
+    /// The value, symbol and symbol style of each state, in cycling order
+    states: Vec<(T, Cow<'a, str>, Style)>,
+    /// Index of the current state
+    index: usize,
+}
+
+impl<'a, T> CycleBox<'a, T> {
+    /// Creates a new cycle box with the given label and `(value, symbol, style)` states, starting
+    /// at the first state.
+    pub fn new<L, I, S>(label: L, states: I) -> Self
+    where
+        L: Into<Line<'a>>,
+        I: IntoIterator<Item = (T, S, Style)>,
+        S: Into<Cow<'a, str>>,
+    {
+        Self {
+            checkbox: Checkbox::new(label, true),
+            states: states
+                .into_iter()
+                .map(|(value, symbol, style)| (value, symbol.into(), style))
+                .collect(),
+            index: 0,
+        }
+    }
+
+    /// Sets the checkbox that draws the label and the symbol, for its layout and styles.
+    ///
+    /// The symbol of the checkbox is replaced by the symbol of the current state.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::style::Style;
+    /// use tui_checkbox::{Checkbox, CycleBox, LabelPosition};
+    ///
+    /// let cycle = CycleBox::new("", [(1, "①", Style::default()), (2, "②", Style::default())])
+    ///     .checkbox(Checkbox::new("Level", true).label_position(LabelPosition::Left));
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn checkbox(mut self, checkbox: Checkbox<'a>) -> Self {
+        self.checkbox = checkbox;
+        self
+    }
+
+    /// Sets the current state, clamped to the last one.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn with_index(mut self, index: usize) -> Self {
+        self.set_index(index);
+        self
+    }
+
+    /// Returns the index of the current state.
+    #[must_use]
+    pub const fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Sets the current state, clamped to the last one.
+    pub fn set_index(&mut self, index: usize) {
+        self.index = index.min(self.states.len().saturating_sub(1));
+    }
+
+    /// Returns the value of the current state, or `None` when there are no states.
+    #[must_use]
+    pub fn value(&self) -> Option<&T> {
+        self.states.get(self.index).map(|(value, _, _)| value)
+    }
+
+    /// Returns the number of states.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.states.len()
+    }
+
+    /// Returns whether there are no states.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.states.is_empty()
+    }
+
+    /// Advances to the next state, wrapping around from the last to the first.
+    pub fn toggle(&mut self) {
+        if !self.states.is_empty() {
+            self.index = (self.index + 1) % self.states.len();
+        }
+    }
+}
+
+impl<T> Widget for CycleBox<'_, T> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        Widget::render(&self, area, buf);
+    }
+}
+
+impl<T> Widget for &CycleBox<'_, T> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let mut checkbox = self.checkbox.clone();
+        if let Some((_, symbol, style)) = self.states.get(self.index) {
+            checkbox.checked = true;
+            checkbox.indeterminate = false;
+            checkbox.checked_symbol = symbol.as_ref().into();
+            checkbox.checked_symbol_line = None;
+            checkbox.checkbox_style = checkbox.checkbox_style.patch(*style);
+        }
+        Widget::render(&checkbox, area, buf);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::style::Color;
+
+    use super::*;
+
+    fn filter() -> CycleBox<'static, &'static str> {
+        CycleBox::new(
+            "Archived",
+            [
+                ("include", "[+]", Style::default().fg(Color::Green)),
+                ("exclude", "[-]", Style::default().fg(Color::Red)),
+                ("ignore", "[ ]", Style::default()),
+            ],
+        )
+    }
+
+    #[test]
+    fn cycle_toggle_wraps() {
+        let mut cycle = filter();
+        let mut values = Vec::new();
+        for _ in 0..4 {
+            values.push(*cycle.value().unwrap());
+            cycle.toggle();
+        }
+        assert_eq!(values, ["include", "exclude", "ignore", "include"]);
+        assert_eq!(cycle.index(), 1);
+    }
+
+    #[test]
+    fn cycle_renders_current_state() {
+        let cycle = filter().with_index(1);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 12, 1));
+        cycle.render(buffer.area, &mut buffer);
+
+        assert_eq!(crate::buffer_rows(&buffer), ["[-] Archived"]);
+        assert_eq!(buffer[(1, 0)].fg, Color::Red);
+        assert_eq!(buffer[(4, 0)].fg, Color::Reset);
+    }
+
+    #[test]
+    fn cycle_index_clamps() {
+        assert_eq!(filter().with_index(7).index(), 2);
+
+        let mut empty = CycleBox::<u8>::new("Empty", Vec::<(u8, &str, Style)>::new());
+        empty.toggle();
+        assert_eq!(empty.value(), None);
+        assert!(empty.is_empty());
+    }
+}
@@ -0,0 +1,486 @@
+//! Sections of [`Checkbox`] widgets with a single cursor across them
+//!
+//! [`Checkbox`]: crate::Checkbox
+
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::{Modifier, Style, Styled};
+use ratatui::text::Line;
+use ratatui::widgets::{Block, StatefulWidget, Widget};
+
+use crate::Checkbox;
+
+/// A widget that renders a form of titled sections of checkboxes with one selection cursor.
+///
+/// Each section starts with its title on a row of its own, in
+/// [`CheckboxForm::header_style`], followed by its checkboxes, each taking the rows it measures
+/// at the available width. Sections are separated by [`CheckboxForm::section_spacing`] blank
+/// rows. A section with an empty title has no header row.
+///
+/// The form is rendered with a [`CheckboxFormState`], which numbers the checkboxes of all
+/// sections in order, so the cursor moves across section boundaries and never lands on a
+/// header. [`CheckboxForm::position`] and [`CheckboxForm::index`] convert between that index
+/// and a `(section, item)` pair. The state's checked values take precedence over
+/// [`Checkbox::checked`], and the selected checkbox is focused and highlighted with
+/// [`CheckboxForm::highlight_style`].
+///
+/// # Examples
+///
+/// ```
+/// use ratatui::buffer::Buffer;
+/// use ratatui::layout::Rect;
+/// use ratatui::style::{Style, Stylize};
+/// use ratatui::widgets::StatefulWidget;
+/// use tui_checkbox::{Checkbox, CheckboxForm};
+///
+/// let form = CheckboxForm::new([
+///     ("Editor", vec![Checkbox::new("Line numbers", true), Checkbox::new("Minimap", false)]),
+///     ("Files", vec![Checkbox::new("Auto-save", false)]),
+/// ])
+/// .highlight_style(Style::new().reversed());
+///
+/// let mut state = form.state();
+/// state.next();
+/// state.next();
+/// state.toggle_selected();
+/// assert_eq!(form.position(state.selected().unwrap()), Some((1, 0)));
+///
+/// let mut buffer = Buffer::empty(Rect::new(0, 0, 20, 6));
+/// form.render(buffer.area, &mut buffer, &mut state);
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct CheckboxForm<'a> {
+    /// The titled sections, from top to bottom
+    sections: Vec<(Line<'a>, Vec<Checkbox<'a>>)>,
+    /// Optional block to wrap the form
+    block: Option<Block<'a>>,
+    /// Base style for the entire form
+    style: Style,
+    /// Style patched onto the selected checkbox
+    highlight_style: Style,
+    /// Style of the section titles
+    header_style: Style,
+    /// Blank rows between consecutive sections
+    section_spacing: u16,
+}
+
+impl Default for CheckboxForm<'_> {
+    /// Returns an empty form with bold section titles and one blank row between sections.
+    fn default() -> Self {
+        Self {
+            sections: Vec::new(),
+            block: None,
+            style: Style::default(),
+            highlight_style: Style::default(),
+            header_style: Style::default().add_modifier(Modifier::BOLD),
+            section_spacing: 1,
+        }
+    }
+}
+
+impl<'a> CheckboxForm<'a> {
+    /// Creates a new form from `(title, checkboxes)` sections.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tui_checkbox::{Checkbox, CheckboxForm};
+    ///
+    /// let form = CheckboxForm::new([
+    ///     ("Editor", vec![Checkbox::new("Line numbers", true)]),
+    ///     ("Files", vec![Checkbox::new("Auto-save", false)]),
+    /// ]);
+    /// ```
+    #[must_use]
+    pub fn new<I, T>(sections: I) -> Self
+    where
+        I: IntoIterator<Item = (T, Vec<Checkbox<'a>>)>,
+        T: Into<Line<'a>>,
+    {
+        Self {
+            sections: sections
+                .into_iter()
+                .map(|(title, items)| (title.into(), items))
+                .collect(),
+            ..Self::default()
+        }
+    }
+
+    /// Appends a section below the existing ones.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn section<T>(mut self, title: T, items: Vec<Checkbox<'a>>) -> Self
+    where
+        T: Into<Line<'a>>,
+    {
+        self.sections.push((title.into(), items));
+        self
+    }
+
+    /// Wraps the form with the given block.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = Some(block);
+        self
+    }
+
+    /// Sets the base style of the form.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets the style patched onto the base style of the selected checkbox.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn highlight_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.highlight_style = style.into();
+        self
+    }
+
+    /// Sets the style of the section titles.
+    ///
+    /// The default is bold.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn header_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.header_style = style.into();
+        self
+    }
+
+    /// Sets the number of blank rows between consecutive sections.
+    ///
+    /// The default is `1`.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn section_spacing(mut self, spacing: u16) -> Self {
+        self.section_spacing = spacing;
+        self
+    }
+
+    /// Returns the number of checkboxes across all sections.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.sections.iter().map(|(_, items)| items.len()).sum()
+    }
+
+    /// Returns whether the form has no checkboxes.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns a state with the checked values of the checkboxes and the first one selected.
+    #[must_use]
+    pub fn state(&self) -> CheckboxFormState {
+        let checked = self
+            .sections
+            .iter()
+            .flat_map(|(_, items)| items)
+            .map(Checkbox::is_checked)
+            .collect();
+        CheckboxFormState::new(checked).with_selected(Some(0))
+    }
+
+    /// Returns the `(section, item)` position of the checkbox at `index` in the form, or `None`
+    /// when the form has fewer checkboxes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tui_checkbox::{Checkbox, CheckboxForm};
+    ///
+    /// let form = CheckboxForm::new([
+    ///     ("A", vec![Checkbox::new("One", false), Checkbox::new("Two", false)]),
+    ///     ("B", vec![Checkbox::new("Three", false)]),
+    /// ]);
+    /// assert_eq!(form.position(2), Some((1, 0)));
+    /// ```
+    #[must_use]
+    pub fn position(&self, index: usize) -> Option<(usize, usize)> {
+        let mut start = 0;
+        for (section, (_, items)) in self.sections.iter().enumerate() {
+            if index < start + items.len() {
+                return Some((section, index - start));
+            }
+            start += items.len();
+        }
+        None
+    }
+
+    /// Returns the index in the form of the checkbox at `item` in `section`, or `None` when
+    /// there is no such checkbox.
+    #[must_use]
+    pub fn index(&self, section: usize, item: usize) -> Option<usize> {
+        let (_, items) = self.sections.get(section)?;
+        if item >= items.len() {
+            return None;
+        }
+        let start: usize = self.sections[..section]
+            .iter()
+            .map(|(_, items)| items.len())
+            .sum();
+        Some(start + item)
+    }
+}
+
+impl Styled for CheckboxForm<'_> {
+    type Item = Self;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style<S: Into<Style>>(mut self, style: S) -> Self::Item {
+        self.style = style.into();
+        self
+    }
+}
+
+/// State of a [`CheckboxForm`]: the selected checkbox and the checked state of every checkbox,
+/// indexed across all sections in order.
+///
+/// Unlike a [`CheckboxGroupState`](crate::CheckboxGroupState), moving past the last checkbox
+/// wraps around to the first, and the other way around.
+///
+/// # Examples
+///
+/// ```
+/// use tui_checkbox::CheckboxFormState;
+///
+/// let mut state = CheckboxFormState::new(vec![false, true]).with_selected(Some(1));
+/// state.next();
+/// assert_eq!(state.selected(), Some(0));
+/// ```
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
+pub struct CheckboxFormState {
+    /// Index of the selected checkbox
+    selected: Option<usize>,
+    /// Checked state of each checkbox
+    checked: Vec<bool>,
+}
+
+impl CheckboxFormState {
+    /// Creates a new state with no selection and the given checked states.
+    #[must_use]
+    pub const fn new(checked: Vec<bool>) -> Self {
+        Self {
+            selected: None,
+            checked,
+        }
+    }
+
+    /// Sets the selected checkbox.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn with_selected(mut self, selected: Option<usize>) -> Self {
+        self.select(selected);
+        self
+    }
+
+    /// Returns the index of the selected checkbox.
+    #[must_use]
+    pub const fn selected(&self) -> Option<usize> {
+        self.selected
+    }
+
+    /// Selects the checkbox at `index`, clamped to the last one, or clears the selection.
+    pub fn select(&mut self, index: Option<usize>) {
+        self.selected = index
+            .filter(|_| !self.checked.is_empty())
+            .map(|index| index.min(self.checked.len() - 1));
+    }
+
+    /// Returns the checked state of every checkbox.
+    #[must_use]
+    pub fn checked(&self) -> &[bool] {
+        &self.checked
+    }
+
+    /// Returns whether the checkbox at `index` is checked.
+    #[must_use]
+    pub fn is_checked(&self, index: usize) -> bool {
+        self.checked.get(index).copied().unwrap_or(false)
+    }
+
+    /// Sets the checked state of the checkbox at `index`, if it exists.
+    pub fn set_checked(&mut self, index: usize, checked: bool) {
+        if let Some(item) = self.checked.get_mut(index) {
+            *item = checked;
+        }
+    }
+
+    /// Selects the next checkbox, wrapping from the last to the first. Selects the first
+    /// checkbox when nothing is selected.
+    pub fn next(&mut self) {
+        let len = self.checked.len();
+        self.select(Some(
+            self.selected.map_or(0, |index| (index + 1) % len.max(1)),
+        ));
+    }
+
+    /// Selects the previous checkbox, wrapping from the first to the last. Selects the last
+    /// checkbox when nothing is selected.
+    pub fn previous(&mut self) {
+        let last = self.checked.len().saturating_sub(1);
+        self.select(Some(
+            self.selected
+                .map_or(last, |index| index.checked_sub(1).unwrap_or(last)),
+        ));
+    }
+
+    /// Flips the checked state of the selected checkbox.
+    pub fn toggle_selected(&mut self) {
+        if let Some(index) = self.selected {
+            self.set_checked(index, !self.is_checked(index));
+        }
+    }
+}
+
+impl StatefulWidget for CheckboxForm<'_> {
+    type State = CheckboxFormState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        StatefulWidget::render(&self, area, buf, state);
+    }
+}
+
+impl StatefulWidget for &CheckboxForm<'_> {
+    type State = CheckboxFormState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        buf.set_style(area, self.style);
+        let inner = if let Some(ref block) = self.block {
+            let inner_area = block.inner(area);
+            block.render(area, buf);
+            inner_area
+        } else {
+            area
+        };
+
+        let mut y = inner.y;
+        let mut index = 0;
+        for (section, (title, items)) in self.sections.iter().enumerate() {
+            if section > 0 {
+                y = y.saturating_add(self.section_spacing);
+            }
+            if y < inner.bottom() && title.width() > 0 {
+                let row = Rect {
+                    y,
+                    height: 1,
+                    ..inner
+                };
+                buf.set_style(row, self.header_style);
+                title
+                    .clone()
+                    .patch_style(self.header_style)
+                    .render(row, buf);
+                y += 1;
+            }
+            for item in items {
+                if y < inner.bottom() {
+                    let mut checkbox = item.clone();
+                    if let Some(&checked) = state.checked.get(index) {
+                        checkbox.checked = checked;
+                    }
+                    if state.selected == Some(index) {
+                        checkbox.focused = true;
+                        checkbox.style = checkbox.style.patch(self.highlight_style);
+                    }
+                    let height = checkbox.measure(inner.width).1.min(inner.bottom() - y);
+                    Widget::render(&checkbox, Rect { y, height, ..inner }, buf);
+                    y += height;
+                }
+                index += 1;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn form() -> CheckboxForm<'static> {
+        CheckboxForm::new([
+            (
+                "Editor",
+                vec![Checkbox::new("One", true), Checkbox::new("Two", false)],
+            ),
+            ("Files", vec![Checkbox::new("Three", false)]),
+        ])
+    }
+
+    #[test]
+    fn form_renders_sections() {
+        let form = form();
+        let mut state = CheckboxFormState::new(vec![true, false, true]);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 8, 6));
+        form.render(buffer.area, &mut buffer, &mut state);
+
+        assert_eq!(
+            crate::buffer_rows(&buffer),
+            ["Editor", "☑ One", "☐ Two", "", "Files", "☑ Three"]
+        );
+    }
+
+    #[test]
+    fn form_state_from_checkboxes() {
+        let state = form().state();
+        assert_eq!(state.checked(), &[true, false, false]);
+        assert_eq!(state.selected(), Some(0));
+    }
+
+    #[test]
+    fn form_navigation_crosses_sections() {
+        let form = form();
+        let mut state = form.state();
+        state.next();
+        assert_eq!(form.position(state.selected().unwrap()), Some((0, 1)));
+        state.next();
+        assert_eq!(form.position(state.selected().unwrap()), Some((1, 0)));
+        state.previous();
+        assert_eq!(form.position(state.selected().unwrap()), Some((0, 1)));
+    }
+
+    #[test]
+    fn form_navigation_wraps() {
+        let mut state = form().state();
+        state.previous();
+        assert_eq!(state.selected(), Some(2));
+        state.next();
+        assert_eq!(state.selected(), Some(0));
+
+        let mut state = CheckboxFormState::default();
+        state.next();
+        assert_eq!(state.selected(), None);
+    }
+
+    #[test]
+    fn form_indexing() {
+        let form = form();
+        assert_eq!(form.len(), 3);
+        assert_eq!(form.position(0), Some((0, 0)));
+        assert_eq!(form.position(2), Some((1, 0)));
+        assert_eq!(form.position(3), None);
+        assert_eq!(form.index(1, 0), Some(2));
+        assert_eq!(form.index(0, 2), None);
+        assert_eq!(form.index(2, 0), None);
+    }
+
+    #[test]
+    fn form_toggle_selected() {
+        let form = form();
+        let mut state = form.state().with_selected(form.index(1, 0));
+        state.toggle_selected();
+        assert_eq!(state.checked(), &[true, false, true]);
+    }
+
+    #[test]
+    fn form_highlights_selected() {
+        let form = CheckboxForm::new([("", vec![Checkbox::new("One", false)])])
+            .highlight_style(Style::default().add_modifier(Modifier::REVERSED));
+        let mut state = form.state();
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 5, 1));
+        form.render(buffer.area, &mut buffer, &mut state);
+
+        assert_eq!(crate::buffer_rows(&buffer), ["☐ One"]);
+        assert!(buffer[(0, 0)].modifier.contains(Modifier::REVERSED));
+    }
+}
@@ -0,0 +1,812 @@
+//! A vertical list of [`Checkbox`] widgets with a selection cursor
+//!
+//! [`Checkbox`]: crate::Checkbox
+
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::{Modifier, Style, Styled};
+use ratatui::text::Line;
+use ratatui::widgets::{Block, StatefulWidget, Widget};
+
+use crate::{symbols, Checkbox};
+
+/// How the items of a [`CheckboxGroup`] relate to each other when toggled.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Default)]
+pub enum GroupMode {
+    /// Every item is checked independently (default)
+    #[default]
+    Multiple,
+    /// At most one item is checked, like radio buttons: checking an item unchecks the others,
+    /// and items with the default symbols render as [`symbols::RADIO_CHECKED`] and
+    /// [`symbols::RADIO_UNCHECKED`]
+    Radio,
+}
+
+/// An entry of a [`CheckboxGroup`], in rendering order.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+enum Entry<'a> {
+    /// A toggleable checkbox
+    Checkbox(Box<Checkbox<'a>>),
+    /// A section title that is not part of the selection
+    Header(Line<'a>),
+}
+
+/// A widget that renders a column of related checkboxes with a selection cursor.
+///
+/// Each checkbox takes the rows it measures at the available width, followed by
+/// [`CheckboxGroup::spacing`] blank rows. Section headers added with
+/// [`CheckboxGroup::push_header`] take one row each and are not items: the state only counts the
+/// checkboxes, so navigation and toggling skip the headers. The group is rendered with a
+/// [`CheckboxGroupState`], whose checked states take precedence over [`Checkbox::checked`] and
+/// whose selected item is focused and highlighted with [`CheckboxGroup::highlight_style`].
+///
+/// # Examples
+///
+/// ```
+/// use ratatui::buffer::Buffer;
+/// use ratatui::layout::Rect;
+/// use ratatui::style::{Style, Stylize};
+/// use ratatui::widgets::StatefulWidget;
+/// use tui_checkbox::{CheckboxGroup, CheckboxGroupState};
+///
+/// let group = CheckboxGroup::from_labels(["Notifications", "Auto-save", "Dark mode"])
+///     .highlight_style(Style::new().reversed());
+/// let mut state = CheckboxGroupState::new(vec![true, false, false]).with_selected(Some(0));
+/// state.next();
+/// state.toggle_selected();
+///
+/// let mut buffer = Buffer::empty(Rect::new(0, 0, 20, 3));
+/// group.render(buffer.area, &mut buffer, &mut state);
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct CheckboxGroup<'a> {
+    /// The checkboxes and headers, from top to bottom
+    entries: Vec<Entry<'a>>,
+    /// Optional block to wrap the group
+    block: Option<Block<'a>>,
+    /// Base style for the entire group
+    style: Style,
+    /// Style patched onto the selected checkbox
+    highlight_style: Style,
+    /// Blank rows between consecutive checkboxes
+    spacing: u16,
+    /// Style of the section headers
+    header_style: Style,
+    /// Whether to append the checked count to the block title
+    show_count_in_title: bool,
+}
+
+impl Default for CheckboxGroup<'_> {
+    /// Returns an empty group with bold section headers.
+    fn default() -> Self {
+        Self {
+            entries: Vec::new(),
+            block: None,
+            style: Style::default(),
+            highlight_style: Style::default(),
+            spacing: 0,
+            header_style: Style::default().add_modifier(Modifier::BOLD),
+            show_count_in_title: false,
+        }
+    }
+}
+
+impl<'a> CheckboxGroup<'a> {
+    /// Creates a new group from the given checkboxes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tui_checkbox::{Checkbox, CheckboxGroup};
+    ///
+    /// let group = CheckboxGroup::new([
+    ///     Checkbox::new("Notifications", true),
+    ///     Checkbox::new("Auto-save", false),
+    /// ]);
+    /// ```
+    #[must_use]
+    pub fn new<I>(items: I) -> Self
+    where
+        I: IntoIterator<Item = Checkbox<'a>>,
+    {
+        Self {
+            entries: items
+                .into_iter()
+                .map(|item| Entry::Checkbox(Box::new(item)))
+                .collect(),
+            ..Self::default()
+        }
+    }
+
+    /// Creates a new group of unchecked checkboxes with the given labels.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tui_checkbox::CheckboxGroup;
+    ///
+    /// let group = CheckboxGroup::from_labels(["Notifications", "Auto-save"]);
+    /// ```
+    #[must_use]
+    pub fn from_labels<I, T>(labels: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<Line<'a>>,
+    {
+        Self::new(labels.into_iter().map(|label| Checkbox::new(label, false)))
+    }
+
+    /// Appends a checkbox below the existing entries.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn push(mut self, checkbox: Checkbox<'a>) -> Self {
+        self.entries.push(Entry::Checkbox(Box::new(checkbox)));
+        self
+    }
+
+    /// Appends a section header below the existing entries.
+    ///
+    /// Headers render in [`CheckboxGroup::header_style`] and are skipped by the selection, so the
+    /// indices of a [`CheckboxGroupState`] only count checkboxes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tui_checkbox::{Checkbox, CheckboxGroup};
+    ///
+    /// let group = CheckboxGroup::default()
+    ///     .push_header("Editor")
+    ///     .push(Checkbox::new("Line numbers", true))
+    ///     .push_header("Files")
+    ///     .push(Checkbox::new("Auto-save", false));
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn push_header<T>(mut self, title: T) -> Self
+    where
+        T: Into<Line<'a>>,
+    {
+        self.entries.push(Entry::Header(title.into()));
+        self
+    }
+
+    /// Sets the style of the section headers.
+    ///
+    /// The default is bold.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn header_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.header_style = style.into();
+        self
+    }
+
+    /// Wraps the group with the given block.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = Some(block);
+        self
+    }
+
+    /// Sets whether to append the number of checked items to the title of the block, as in
+    /// `┌Settings─(2/5)┐`.
+    ///
+    /// The counter is added as a second title, so it follows the existing title with a border
+    /// cell in between, or stands alone when the block has none. It counts the checkboxes of the
+    /// group, not its headers. It has no effect without a
+    /// [`CheckboxGroup::block`]. The default is `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::widgets::Block;
+    /// use tui_checkbox::CheckboxGroup;
+    ///
+    /// let group = CheckboxGroup::from_labels(["A", "B"])
+    ///     .block(Block::bordered().title("Settings"))
+    ///     .show_count_in_title(true);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn show_count_in_title(mut self, show: bool) -> Self {
+        self.show_count_in_title = show;
+        self
+    }
+
+    /// Sets the base style of the group.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets the style patched onto the base style of the selected checkbox.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::style::{Color, Style};
+    /// use tui_checkbox::CheckboxGroup;
+    ///
+    /// let group = CheckboxGroup::from_labels(["A", "B"])
+    ///     .highlight_style(Style::default().bg(Color::DarkGray));
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn highlight_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.highlight_style = style.into();
+        self
+    }
+
+    /// Sets the number of blank rows between consecutive checkboxes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tui_checkbox::CheckboxGroup;
+    ///
+    /// let group = CheckboxGroup::from_labels(["A", "B"]).spacing(1);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn spacing(mut self, spacing: u16) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Returns the index of the first checkbox whose label contains `substr`, ignoring case.
+    ///
+    /// Like the indices of a [`CheckboxGroupState`], the index only counts checkboxes, not
+    /// headers, so it can be passed to [`CheckboxGroupState::select`] to jump to an option by name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tui_checkbox::CheckboxGroup;
+    ///
+    /// let group = CheckboxGroup::from_labels(["Notifications", "Auto-save", "Dark mode"]);
+    /// assert_eq!(group.find("SAVE"), Some(1));
+    /// assert_eq!(group.find("light"), None);
+    /// ```
+    #[must_use]
+    pub fn find(&self, substr: &str) -> Option<usize> {
+        let substr = substr.to_lowercase();
+        self.entries
+            .iter()
+            .filter_map(|entry| match entry {
+                Entry::Checkbox(item) => Some(item),
+                Entry::Header(_) => None,
+            })
+            .position(|item| item.label.to_string().to_lowercase().contains(&substr))
+    }
+
+    /// Returns the `(checked/total)` counter shown by [`CheckboxGroup::show_count_in_title`],
+    /// reading the checked states from `state` like rendering does.
+    fn count_title(&self, state: &CheckboxGroupState) -> String {
+        let items = self.entries.iter().filter_map(|entry| match entry {
+            Entry::Checkbox(item) => Some(item),
+            Entry::Header(_) => None,
+        });
+        let (mut checked, mut total) = (0, 0);
+        for (index, item) in items.enumerate() {
+            if state.checked.get(index).copied().unwrap_or(item.checked) {
+                checked += 1;
+            }
+            total += 1;
+        }
+        format!("({checked}/{total})")
+    }
+}
+
+impl Styled for CheckboxGroup<'_> {
+    type Item = Self;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style<S: Into<Style>>(mut self, style: S) -> Self::Item {
+        self.style = style.into();
+        self
+    }
+}
+
+/// State of a [`CheckboxGroup`]: the selected item and the checked state of every item.
+///
+/// The length of the checked list is the number of items the selection moves over. In
+/// [`GroupMode::Radio`] the state keeps at most one item checked.
+///
+/// # Examples
+///
+/// ```
+/// use tui_checkbox::CheckboxGroupState;
+///
+/// let mut state = CheckboxGroupState::new(vec![false, false, true]);
+/// state.next();
+/// state.toggle_selected();
+/// assert_eq!(state.selected(), Some(0));
+/// assert_eq!(state.checked(), &[true, false, true]);
+/// ```
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
+pub struct CheckboxGroupState {
+    /// Index of the selected item
+    selected: Option<usize>,
+    /// Checked state of each item
+    checked: Vec<bool>,
+    /// How toggling an item affects the others
+    mode: GroupMode,
+    /// Whether toggling the checked radio item unchecks it
+    allow_deselect: bool,
+}
+
+impl CheckboxGroupState {
+    /// Creates a new state with no selection and the given checked states.
+    #[must_use]
+    pub const fn new(checked: Vec<bool>) -> Self {
+        Self {
+            selected: None,
+            checked,
+            mode: GroupMode::Multiple,
+            allow_deselect: false,
+        }
+    }
+
+    /// Sets how toggling an item affects the others.
+    ///
+    /// Switching to [`GroupMode::Radio`] keeps only the first checked item checked.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tui_checkbox::{CheckboxGroupState, GroupMode};
+    ///
+    /// let state = CheckboxGroupState::new(vec![false, true, true]).with_mode(GroupMode::Radio);
+    /// assert_eq!(state.checked(), &[false, true, false]);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn with_mode(mut self, mode: GroupMode) -> Self {
+        self.mode = mode;
+        if mode == GroupMode::Radio {
+            if let Some(index) = self.selected_value() {
+                self.check_only(index);
+            }
+        }
+        self
+    }
+
+    /// Sets whether toggling the checked item in [`GroupMode::Radio`] unchecks it, leaving no
+    /// item checked. The default is `false`, which keeps it checked.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn allow_deselect(mut self, allow_deselect: bool) -> Self {
+        self.allow_deselect = allow_deselect;
+        self
+    }
+
+    /// Returns how toggling an item affects the others.
+    #[must_use]
+    pub const fn mode(&self) -> GroupMode {
+        self.mode
+    }
+
+    /// Returns the index of the first checked item, which is the only one in
+    /// [`GroupMode::Radio`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tui_checkbox::{CheckboxGroupState, GroupMode};
+    ///
+    /// let mut state = CheckboxGroupState::new(vec![false; 3]).with_mode(GroupMode::Radio);
+    /// state.set_checked(2, true);
+    /// assert_eq!(state.selected_value(), Some(2));
+    /// ```
+    #[must_use]
+    pub fn selected_value(&self) -> Option<usize> {
+        self.checked.iter().position(|&checked| checked)
+    }
+
+    /// Sets the selected item.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn with_selected(mut self, selected: Option<usize>) -> Self {
+        self.select(selected);
+        self
+    }
+
+    /// Returns the index of the selected item.
+    #[must_use]
+    pub const fn selected(&self) -> Option<usize> {
+        self.selected
+    }
+
+    /// Selects the item at `index`, clamped to the last item, or clears the selection.
+    pub fn select(&mut self, index: Option<usize>) {
+        self.selected = index
+            .filter(|_| !self.checked.is_empty())
+            .map(|index| index.min(self.checked.len() - 1));
+    }
+
+    /// Returns the checked state of every item.
+    #[must_use]
+    pub fn checked(&self) -> &[bool] {
+        &self.checked
+    }
+
+    /// Returns whether the item at `index` is checked.
+    #[must_use]
+    pub fn is_checked(&self, index: usize) -> bool {
+        self.checked.get(index).copied().unwrap_or(false)
+    }
+
+    /// Sets the checked state of the item at `index`, if it exists. In [`GroupMode::Radio`],
+    /// checking an item unchecks all the others.
+    ///
+    /// Returns the indices of the items whose checked state changed, in ascending order, so the
+    /// change can be recorded for undo or dirty tracking.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tui_checkbox::{CheckboxGroupState, GroupMode};
+    ///
+    /// let mut state = CheckboxGroupState::new(vec![true, false]).with_mode(GroupMode::Radio);
+    /// assert_eq!(state.set_checked(1, true), [0, 1]);
+    /// assert!(state.set_checked(1, true).is_empty());
+    /// ```
+    pub fn set_checked(&mut self, index: usize, checked: bool) -> Vec<usize> {
+        if index >= self.checked.len() {
+            return Vec::new();
+        }
+        let before = self.checked.clone();
+        if checked && self.mode == GroupMode::Radio {
+            self.check_only(index);
+        } else {
+            self.checked[index] = checked;
+        }
+        before
+            .iter()
+            .zip(&self.checked)
+            .enumerate()
+            .filter(|(_, (before, after))| before != after)
+            .map(|(index, _)| index)
+            .collect()
+    }
+
+    /// Checks the item at `index` and unchecks every other item.
+    fn check_only(&mut self, index: usize) {
+        for (i, item) in self.checked.iter_mut().enumerate() {
+            *item = i == index;
+        }
+    }
+
+    /// Selects the next item, stopping at the last one. Selects the first item when nothing is
+    /// selected.
+    pub fn next(&mut self) {
+        self.select(Some(self.selected.map_or(0, |index| index + 1)));
+    }
+
+    /// Selects the previous item, stopping at the first one. Selects the last item when nothing
+    /// is selected.
+    pub fn previous(&mut self) {
+        self.select(Some(
+            self.selected
+                .map_or(usize::MAX, |index| index.saturating_sub(1)),
+        ));
+    }
+
+    /// Flips the checked state of the selected item.
+    ///
+    /// In [`GroupMode::Radio`] the selected item is checked and the others unchecked. Toggling
+    /// the item that is already checked only unchecks it with
+    /// [`CheckboxGroupState::allow_deselect`].
+    ///
+    /// Returns the indices of the items whose checked state changed, as
+    /// [`CheckboxGroupState::set_checked`] does.
+    pub fn toggle_selected(&mut self) -> Vec<usize> {
+        let Some(index) = self.selected else {
+            return Vec::new();
+        };
+        let checked = self.is_checked(index);
+        if checked && self.mode == GroupMode::Radio && !self.allow_deselect {
+            return Vec::new();
+        }
+        self.set_checked(index, !checked)
+    }
+}
+
+impl StatefulWidget for CheckboxGroup<'_> {
+    type State = CheckboxGroupState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        StatefulWidget::render(&self, area, buf, state);
+    }
+}
+
+impl StatefulWidget for &CheckboxGroup<'_> {
+    type State = CheckboxGroupState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        buf.set_style(area, self.style);
+        let inner = if let Some(ref block) = self.block {
+            let inner_area = block.inner(area);
+            if self.show_count_in_title {
+                // Titles sharing a position follow each other, so the counter comes after any
+                // existing title
+                block
+                    .clone()
+                    .title(self.count_title(state))
+                    .render(area, buf);
+            } else {
+                block.render(area, buf);
+            }
+            inner_area
+        } else {
+            area
+        };
+
+        let mut y = inner.y;
+        let mut index = 0;
+        for entry in &self.entries {
+            if y >= inner.bottom() {
+                break;
+            }
+            let item = match entry {
+                Entry::Checkbox(item) => item,
+                Entry::Header(title) => {
+                    let row = Rect {
+                        y,
+                        height: 1,
+                        ..inner
+                    };
+                    buf.set_style(row, self.header_style);
+                    title
+                        .clone()
+                        .patch_style(self.header_style)
+                        .render(row, buf);
+                    y = y.saturating_add(1).saturating_add(self.spacing);
+                    continue;
+                }
+            };
+            let mut checkbox = Checkbox::clone(item);
+            if let Some(&checked) = state.checked.get(index) {
+                checkbox.checked = checked;
+            }
+            if state.mode == GroupMode::Radio {
+                if checkbox.checked_symbol == symbols::CHECKED {
+                    checkbox.checked_symbol = symbols::RADIO_CHECKED.into();
+                }
+                if checkbox.unchecked_symbol == symbols::UNCHECKED {
+                    checkbox.unchecked_symbol = symbols::RADIO_UNCHECKED.into();
+                }
+            }
+            if state.selected == Some(index) {
+                checkbox.focused = true;
+                checkbox.style = checkbox.style.patch(self.highlight_style);
+            }
+
+            let height = checkbox.measure(inner.width).1.min(inner.bottom() - y);
+            Widget::render(&checkbox, Rect { y, height, ..inner }, buf);
+            y = y.saturating_add(height).saturating_add(self.spacing);
+            index += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::style::{Color, Modifier};
+
+    use super::*;
+
+    #[test]
+    fn group_find_by_label() {
+        let group = CheckboxGroup::default()
+            .push_header("Editor")
+            .push(Checkbox::new("Line numbers", true))
+            .push_header("Files")
+            .push(Checkbox::new("Auto-save", false))
+            .push(Checkbox::new("Save on exit", false));
+
+        assert_eq!(group.find("save"), Some(1));
+        assert_eq!(group.find("ON EXIT"), Some(2));
+        assert_eq!(group.find("line"), Some(0));
+    }
+
+    #[test]
+    fn group_find_not_found() {
+        let group = CheckboxGroup::from_labels(["Notifications", "Auto-save"]);
+        assert_eq!(group.find("dark"), None);
+        // Headers are not items
+        assert_eq!(group.push_header("Display").find("display"), None);
+    }
+
+    #[test]
+    fn group_count_title() {
+        let group = CheckboxGroup::from_labels(["One", "Two", "Three"]).push_header("More");
+        let state = CheckboxGroupState::new(vec![true, false, true]);
+        assert_eq!(group.count_title(&state), "(2/3)");
+        assert_eq!(group.count_title(&CheckboxGroupState::default()), "(0/3)");
+    }
+
+    #[test]
+    fn group_count_appended_to_title() {
+        let group = CheckboxGroup::from_labels(["One", "Two"])
+            .block(Block::bordered().title("Settings"))
+            .show_count_in_title(true);
+        let mut state = CheckboxGroupState::new(vec![true, false]);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 18, 4));
+        group.render(buffer.area, &mut buffer, &mut state);
+
+        assert_eq!(
+            buffer,
+            Buffer::with_lines([
+                "┌Settings─(1/2)──┐",
+                "│☑ One           │",
+                "│☐ Two           │",
+                "└────────────────┘",
+            ])
+        );
+    }
+
+    #[test]
+    fn group_count_without_title() {
+        let group = CheckboxGroup::from_labels(["One"])
+            .block(Block::bordered())
+            .show_count_in_title(true);
+        let mut state = CheckboxGroupState::new(vec![true]);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 9, 3));
+        group.render(buffer.area, &mut buffer, &mut state);
+
+        assert_eq!(crate::buffer_rows(&buffer)[0], "┌(1/1)──┐");
+    }
+
+    #[test]
+    fn group_rows() {
+        let group = CheckboxGroup::from_labels(["One", "Two", "Three"]);
+        let mut state = CheckboxGroupState::new(vec![false, true, false]);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 8, 3));
+        group.render(buffer.area, &mut buffer, &mut state);
+
+        assert_eq!(
+            buffer,
+            Buffer::with_lines(["☐ One   ", "☑ Two   ", "☐ Three "])
+        );
+    }
+
+    #[test]
+    fn group_spacing_and_wrapped_items() {
+        let group = CheckboxGroup::new([
+            Checkbox::new("One two", false).wrap_label(true),
+            Checkbox::new("Three", false),
+        ])
+        .spacing(1);
+        let mut state = CheckboxGroupState::new(vec![false, false]);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 6, 5));
+        group.render(buffer.area, &mut buffer, &mut state);
+
+        assert_eq!(
+            buffer,
+            Buffer::with_lines(["☐ One ", "  two ", "      ", "☐ Thre", "      "])
+        );
+    }
+
+    #[test]
+    fn group_highlights_selected_row() {
+        let highlight = Style::default()
+            .bg(Color::Blue)
+            .add_modifier(Modifier::BOLD);
+        let group = CheckboxGroup::from_labels(["One", "Two"]).highlight_style(highlight);
+        let mut state = CheckboxGroupState::new(vec![false, false]).with_selected(Some(1));
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 6, 2));
+        group.render(buffer.area, &mut buffer, &mut state);
+
+        for x in 0..6 {
+            assert_ne!(buffer[(x, 0)].style().bg, Some(Color::Blue));
+            assert_eq!(buffer[(x, 1)].style().bg, Some(Color::Blue));
+        }
+    }
+
+    #[test]
+    fn group_state_navigation() {
+        let mut state = CheckboxGroupState::new(vec![false; 3]);
+        state.previous();
+        assert_eq!(state.selected(), Some(2));
+        state.next();
+        assert_eq!(state.selected(), Some(2));
+        state.previous();
+        state.previous();
+        state.previous();
+        assert_eq!(state.selected(), Some(0));
+
+        state.toggle_selected();
+        assert_eq!(state.checked(), &[true, false, false]);
+        state.toggle_selected();
+        assert!(!state.is_checked(0));
+    }
+
+    #[test]
+    fn group_state_empty() {
+        let mut state = CheckboxGroupState::default();
+        state.next();
+        state.toggle_selected();
+        assert_eq!(state.selected(), None);
+    }
+
+    #[test]
+    fn radio_keeps_one_checked() {
+        let mut state = CheckboxGroupState::new(vec![false; 4]).with_mode(GroupMode::Radio);
+        for index in [2, 0, 3, 3, 1] {
+            state.select(Some(index));
+            state.toggle_selected();
+            assert_eq!(state.checked().iter().filter(|&&c| c).count(), 1);
+            assert_eq!(state.selected_value(), Some(index));
+        }
+
+        state.set_checked(2, true);
+        assert_eq!(state.checked(), &[false, false, true, false]);
+        state.set_checked(2, false);
+        assert_eq!(state.selected_value(), None);
+    }
+
+    #[test]
+    fn toggle_returns_changed_index() {
+        let mut state = CheckboxGroupState::new(vec![false, true, false]).with_selected(Some(2));
+        assert_eq!(state.toggle_selected(), [2]);
+        assert_eq!(state.toggle_selected(), [2]);
+        assert!(state.set_checked(1, true).is_empty());
+        assert!(state.set_checked(5, true).is_empty());
+    }
+
+    #[test]
+    fn radio_returns_all_changed_indices() {
+        let mut state = CheckboxGroupState::new(vec![false, true, false])
+            .with_mode(GroupMode::Radio)
+            .with_selected(Some(2));
+        assert_eq!(state.toggle_selected(), [1, 2]);
+        assert!(state.toggle_selected().is_empty());
+        assert_eq!(state.set_checked(0, true), [0, 2]);
+    }
+
+    #[test]
+    fn radio_allow_deselect() {
+        let mut state = CheckboxGroupState::new(vec![false, true])
+            .with_mode(GroupMode::Radio)
+            .allow_deselect(true)
+            .with_selected(Some(1));
+        state.toggle_selected();
+        assert_eq!(state.selected_value(), None);
+        state.toggle_selected();
+        assert_eq!(state.selected_value(), Some(1));
+    }
+
+    #[test]
+    fn radio_renders_round_symbols() {
+        let group = CheckboxGroup::new([
+            Checkbox::new("One", false),
+            Checkbox::new("Two", false).checked_symbol("[x]"),
+        ]);
+        let mut state = CheckboxGroupState::new(vec![false, true]).with_mode(GroupMode::Radio);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 7, 2));
+        group.render(buffer.area, &mut buffer, &mut state);
+
+        assert_eq!(buffer, Buffer::with_lines(["○ One  ", "[x] Two"]));
+    }
+
+    #[test]
+    fn group_headers() {
+        let group = CheckboxGroup::default()
+            .push_header("Editor")
+            .push(Checkbox::new("One", false))
+            .push_header("Files")
+            .push(Checkbox::new("Two", false))
+            .highlight_style(Style::default().bg(Color::Blue));
+        let mut state = CheckboxGroupState::new(vec![false, false]).with_selected(Some(0));
+        state.next();
+        state.toggle_selected();
+        assert_eq!(state.selected(), Some(1));
+
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 6, 4));
+        (&group).render(buffer.area, &mut buffer, &mut state);
+
+        let mut expected = Buffer::with_lines(["Editor", "☐ One ", "Files ", "☑ Two "]);
+        expected.set_style(Rect::new(0, 0, 6, 1), Modifier::BOLD);
+        expected.set_style(Rect::new(0, 2, 6, 1), Modifier::BOLD);
+        expected.set_style(Rect::new(0, 3, 6, 1), Style::default().bg(Color::Blue));
+        // The selected checkbox is focused, which underlines its content
+        expected.set_style(Rect::new(0, 3, 1, 1), Modifier::UNDERLINED);
+        expected.set_style(Rect::new(2, 3, 3, 1), Modifier::UNDERLINED);
+        assert_eq!(buffer, expected);
+    }
+}
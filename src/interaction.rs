@@ -0,0 +1,98 @@
+//! Key handling for interactive checkboxes
+//!
+//! This module is only available with the `crossterm` feature.
+
+use ratatui::crossterm::event::KeyCode;
+
+use crate::CheckboxState;
+
+/// The keys that toggle a checkbox in [`handle_key`]: Space and Enter.
+pub const DEFAULT_TOGGLE_KEYS: [KeyCode; 2] = [KeyCode::Char(' '), KeyCode::Enter];
+
+/// Toggles `state` when `key` is Space or Enter, and returns whether the key was consumed.
+///
+/// Keys that don't toggle are left for the application to handle, such as moving focus between
+/// checkboxes. Use [`handle_key_with`] to toggle on a different set of keys.
+///
+/// # Examples
+///
+/// ```
+/// use ratatui::crossterm::event::KeyCode;
+/// use tui_checkbox::interaction::handle_key;
+/// use tui_checkbox::CheckboxState;
+///
+/// let mut state = CheckboxState::new(false);
+/// assert!(handle_key(&mut state, KeyCode::Char(' ')));
+/// assert!(state.is_checked());
+/// assert!(!handle_key(&mut state, KeyCode::Tab));
+/// ```
+pub fn handle_key(state: &mut CheckboxState, key: KeyCode) -> bool {
+    handle_key_with(state, key, &DEFAULT_TOGGLE_KEYS)
+}
+
+/// Toggles `state` when `key` is one of `toggle_keys`, and returns whether the key was consumed.
+///
+/// # Examples
+///
+/// ```
+/// use ratatui::crossterm::event::KeyCode;
+/// use tui_checkbox::interaction::handle_key_with;
+/// use tui_checkbox::CheckboxState;
+///
+/// let mut state = CheckboxState::new(false);
+/// let keys = [KeyCode::Char('x')];
+/// assert!(!handle_key_with(&mut state, KeyCode::Enter, &keys));
+/// assert!(handle_key_with(&mut state, KeyCode::Char('x'), &keys));
+/// assert!(state.is_checked());
+/// ```
+pub fn handle_key_with(state: &mut CheckboxState, key: KeyCode, toggle_keys: &[KeyCode]) -> bool {
+    let consumed = toggle_keys.contains(&key);
+    if consumed {
+        state.toggle();
+    }
+    consumed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn handle_key_toggles_on_default_keys() {
+        let mut state = CheckboxState::new(false);
+
+        assert!(handle_key(&mut state, KeyCode::Char(' ')));
+        assert!(state.is_checked());
+        assert!(handle_key(&mut state, KeyCode::Enter));
+        assert!(!state.is_checked());
+        assert!(state.take_changed());
+    }
+
+    #[test]
+    fn handle_key_ignores_other_keys() {
+        let mut state = CheckboxState::new(true);
+
+        for key in [
+            KeyCode::Tab,
+            KeyCode::Char('x'),
+            KeyCode::Esc,
+            KeyCode::Down,
+        ] {
+            assert!(!handle_key(&mut state, key));
+        }
+        assert!(state.is_checked());
+        assert!(!state.take_changed());
+    }
+
+    #[test]
+    fn handle_key_with_custom_keys() {
+        let mut state = CheckboxState::new(false);
+        let keys = [KeyCode::Char('x'), KeyCode::Char('X')];
+
+        assert!(!handle_key_with(&mut state, KeyCode::Char(' '), &keys));
+        assert!(!state.is_checked());
+        assert!(handle_key_with(&mut state, KeyCode::Char('X'), &keys));
+        assert!(state.is_checked());
+        assert!(!handle_key_with(&mut state, KeyCode::Enter, &[]));
+    }
+}
@@ -0,0 +1,138 @@
+//! Building a [`Checkbox`] from a loosely structured JSON object
+//!
+//! [`Checkbox`]: crate::Checkbox
+
+use serde::Deserialize;
+use serde_json::Value;
+
+use crate::{Checkbox, HorizontalAlignment, LabelPosition, VerticalAlignment};
+
+/// The JSON object accepted by [`Checkbox::from_json_value`]. Unknown keys are ignored.
+#[derive(Deserialize)]
+struct Config {
+    #[serde(default)]
+    label: String,
+    #[serde(default)]
+    checked: bool,
+    #[serde(default)]
+    symbols: Symbols,
+    position: Option<LabelPosition>,
+    horizontal_alignment: Option<HorizontalAlignment>,
+    vertical_alignment: Option<VerticalAlignment>,
+    min_width: Option<u16>,
+    max_width: Option<u16>,
+    #[serde(default)]
+    wrap: bool,
+}
+
+/// The `symbols` object of a [`Config`].
+#[derive(Default, Deserialize)]
+struct Symbols {
+    checked: Option<String>,
+    unchecked: Option<String>,
+}
+
+impl Checkbox<'static> {
+    /// Creates a checkbox from a JSON object, for configuration-driven interfaces.
+    ///
+    /// The object may contain the following keys, all optional, and any other key is ignored:
+    ///
+    /// - `label`: the label text
+    /// - `checked`: whether the checkbox is checked
+    /// - `symbols`: an object with `checked` and `unchecked` symbol strings
+    /// - `position`: `"right"`, `"left"`, `"top"` or `"bottom"`
+    /// - `horizontal_alignment`: `"left"`, `"center"` or `"right"`
+    /// - `vertical_alignment`: `"top"`, `"center"` or `"bottom"`
+    /// - `min_width` and `max_width`: width constraints
+    /// - `wrap`: whether to wrap the label
+    ///
+    /// This requires the `serde` feature.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when `value` is not an object or a known key has a value of the wrong
+    /// type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use serde_json::json;
+    /// use tui_checkbox::Checkbox;
+    ///
+    /// let checkbox = Checkbox::from_json_value(json!({
+    ///     "label": "Enable feature",
+    ///     "checked": true,
+    ///     "symbols": { "checked": "[x]", "unchecked": "[ ]" },
+    ///     "position": "left",
+    /// }))?;
+    /// # Ok::<(), serde_json::Error>(())
+    /// ```
+    pub fn from_json_value(value: Value) -> Result<Self, serde_json::Error> {
+        let config = Config::deserialize(value)?;
+        let mut checkbox = Checkbox::new(config.label, config.checked).wrap_label(config.wrap);
+        if let Some(symbol) = config.symbols.checked {
+            checkbox = checkbox.checked_symbol(symbol);
+        }
+        if let Some(symbol) = config.symbols.unchecked {
+            checkbox = checkbox.unchecked_symbol(symbol);
+        }
+        if let Some(position) = config.position {
+            checkbox = checkbox.label_position(position);
+        }
+        if let Some(alignment) = config.horizontal_alignment {
+            checkbox = checkbox.horizontal_alignment(alignment);
+        }
+        if let Some(alignment) = config.vertical_alignment {
+            checkbox = checkbox.vertical_alignment(alignment);
+        }
+        checkbox.min_width = config.min_width;
+        checkbox.max_width = config.max_width;
+        Ok(checkbox)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn from_json_value() {
+        let checkbox = Checkbox::from_json_value(json!({
+            "label": "Enable feature",
+            "checked": true,
+            "symbols": { "checked": "[x]", "unchecked": "[ ]" },
+            "position": "left",
+            "horizontal_alignment": "center",
+            "max_width": 20,
+            "wrap": true,
+            "plugin": { "id": 7 },
+        }))
+        .unwrap();
+
+        assert_eq!(
+            checkbox,
+            Checkbox::new("Enable feature", true)
+                .checked_symbol("[x]")
+                .unchecked_symbol("[ ]")
+                .label_position(LabelPosition::Left)
+                .horizontal_alignment(HorizontalAlignment::Center)
+                .max_width(20)
+                .wrap_label(true)
+        );
+    }
+
+    #[test]
+    fn from_json_value_defaults() {
+        let checkbox = Checkbox::from_json_value(json!({ "label": "Option" })).unwrap();
+        assert_eq!(checkbox, Checkbox::new("Option", false));
+    }
+
+    #[test]
+    fn from_json_value_errors() {
+        assert!(Checkbox::from_json_value(json!("Option")).is_err());
+        assert!(Checkbox::from_json_value(json!({ "checked": "yes" })).is_err());
+        assert!(Checkbox::from_json_value(json!({ "position": "middle" })).is_err());
+    }
+}
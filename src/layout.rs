@@ -0,0 +1,373 @@
+//! Layout utilities shared with the [`Checkbox`] widget
+//!
+//! [`Checkbox`]: crate::Checkbox
+
+use ratatui::layout::Rect;
+use ratatui::style::Style;
+use ratatui::text::{Line, Span};
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+use crate::WrapMode;
+
+/// Wraps `line` into lines that each fit in `max_width` columns, as a wrapped checkbox label is.
+///
+/// Each character keeps the style of its span, and a word that continues across spans keeps
+/// one span per style. Widths are measured in terminal columns, so wide glyphs such as CJK
+/// characters and emoji count double and are never split across lines. `mode` decides where
+/// lines may break; see [`WrapMode`]. An empty line, or a `max_width` of zero, yields the line
+/// unchanged.
+///
+/// This is useful to compute the height of a label before laying it out.
+///
+/// # Examples
+///
+/// ```
+/// use ratatui::text::Line;
+/// use tui_checkbox::layout::wrap_line;
+/// use tui_checkbox::WrapMode;
+///
+/// let lines = wrap_line(&Line::from("Enable all features"), 10, WrapMode::WordThenChar);
+/// let text: Vec<String> = lines.iter().map(ToString::to_string).collect();
+/// assert_eq!(text, ["Enable all", "features"]);
+/// ```
+#[must_use]
+pub fn wrap_line(line: &Line<'_>, max_width: u16, mode: WrapMode) -> Vec<Line<'static>> {
+    if max_width == 0 {
+        let owned = Line::from(
+            line.spans
+                .iter()
+                .map(|s| Span::styled(s.content.to_string(), s.style))
+                .collect::<Vec<_>>(),
+        );
+        return vec![owned];
+    }
+
+    // Breaking at any character treats the whole line, spaces included, as a single word
+    if mode == WrapMode::Char {
+        let line = WrapWord {
+            fragments: line
+                .spans
+                .iter()
+                .map(|s| Span::styled(s.content.to_string(), s.style))
+                .collect(),
+            width: line.width() as u16,
+            space_style: Style::default(),
+        };
+        return line
+            .split(max_width)
+            .into_iter()
+            .map(|piece| Line::from(piece.fragments))
+            .collect();
+    }
+
+    // Split the label into words on spaces only. A word can continue across span boundaries
+    // (e.g. a differently styled suffix), so each word keeps one fragment per span it covers.
+    let mut words = Vec::new();
+    let mut word = WrapWord::default();
+    for span in &line.spans {
+        for (i, piece) in span.content.split(' ').enumerate() {
+            if i > 0 {
+                let next = WrapWord {
+                    space_style: span.style,
+                    ..WrapWord::default()
+                };
+                words.push(std::mem::replace(&mut word, next));
+            }
+            if !piece.is_empty() {
+                word.fragments
+                    .push(Span::styled(piece.to_string(), span.style));
+                word.width += piece.width() as u16;
+            }
+        }
+    }
+    words.push(word);
+
+    let mut result = Vec::new();
+    let mut current_line = Vec::new();
+    let mut current_width = 0u16;
+
+    let words = words.into_iter().flat_map(|word| {
+        if mode == WrapMode::Word {
+            vec![word]
+        } else {
+            word.split(max_width)
+        }
+    });
+    for word in words {
+        let space_width = u16::from(current_width > 0);
+        if current_width > 0 && current_width + space_width + word.width > max_width {
+            result.push(Line::from(std::mem::take(&mut current_line)));
+            current_width = 0;
+        }
+
+        if current_width > 0 {
+            current_line.push(Span::styled(String::from(" "), word.space_style));
+            current_width += 1;
+        }
+
+        current_line.extend(word.fragments);
+        current_width += word.width;
+    }
+
+    if !current_line.is_empty() {
+        result.push(Line::from(current_line));
+    }
+
+    if result.is_empty() {
+        let owned = Line::from(
+            line.spans
+                .iter()
+                .map(|s| Span::styled(s.content.to_string(), s.style))
+                .collect::<Vec<_>>(),
+        );
+        result.push(owned);
+    }
+
+    result
+}
+
+/// Returns the row of each of `count` single-row checkboxes stacked from the top of `area`, with
+/// `spacing` blank rows between them, as a [`CheckboxGroup`] lays them out.
+///
+/// Each row spans the full width of `area`. Rows that would start below `area` are left out, so
+/// fewer than `count` rows are returned when the checkboxes don't all fit. This gives the
+/// geometry of a manually rendered column, for example to map a mouse click to a checkbox.
+///
+/// [`CheckboxGroup`]: crate::CheckboxGroup
+///
+/// # Examples
+///
+/// ```
+/// use ratatui::layout::Rect;
+/// use tui_checkbox::layout::stack_checkboxes;
+///
+/// let rows = stack_checkboxes(Rect::new(0, 0, 20, 10), 3, 1);
+/// assert_eq!(rows[1], Rect::new(0, 2, 20, 1));
+/// ```
+#[must_use]
+pub fn stack_checkboxes(area: Rect, count: usize, spacing: u16) -> Vec<Rect> {
+    let step = spacing.saturating_add(1);
+    (0..count)
+        .map_while(|index| {
+            let offset = u16::try_from(index).ok()?.checked_mul(step)?;
+            let y = area.y.checked_add(offset).filter(|&y| y < area.bottom())?;
+            Some(Rect {
+                y,
+                height: 1,
+                ..area
+            })
+        })
+        .collect()
+}
+
+/// A space-separated word of a label, used while wrapping.
+#[derive(Default)]
+struct WrapWord {
+    /// The word text, split into one span per style it spans
+    fragments: Vec<Span<'static>>,
+    /// Display width of the word
+    width: u16,
+    /// Style of the space preceding the word
+    space_style: Style,
+}
+
+impl WrapWord {
+    /// Breaks a word wider than `max_width` into pieces that each fit on a line, without
+    /// splitting a wide glyph or a grapheme cluster, such as a letter and its combining accent
+    /// or an emoji sequence. A glyph wider than `max_width` gets a piece of its own.
+    fn split(self, max_width: u16) -> Vec<WrapWord> {
+        if self.width <= max_width {
+            return vec![self];
+        }
+
+        let mut pieces = vec![WrapWord {
+            space_style: self.space_style,
+            ..WrapWord::default()
+        }];
+        for fragment in &self.fragments {
+            for grapheme in fragment.content.graphemes(true) {
+                let width = grapheme.width() as u16;
+                let piece = pieces.last_mut().expect("pieces is never empty");
+                if piece.width > 0 && piece.width + width > max_width {
+                    pieces.push(WrapWord::default());
+                }
+                let piece = pieces.last_mut().expect("pieces is never empty");
+                match piece.fragments.last_mut() {
+                    Some(span) if span.style == fragment.style => {
+                        span.content.to_mut().push_str(grapheme);
+                    }
+                    _ => piece
+                        .fragments
+                        .push(Span::styled(grapheme.to_string(), fragment.style)),
+                }
+                piece.width += width;
+            }
+        }
+        pieces
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::style::{Color, Stylize};
+
+    use super::*;
+
+    #[test]
+    fn stack_checkboxes_without_spacing() {
+        let rows = stack_checkboxes(Rect::new(2, 1, 10, 5), 3, 0);
+        assert_eq!(
+            rows,
+            [
+                Rect::new(2, 1, 10, 1),
+                Rect::new(2, 2, 10, 1),
+                Rect::new(2, 3, 10, 1),
+            ]
+        );
+    }
+
+    #[test]
+    fn stack_checkboxes_with_spacing() {
+        let rows = stack_checkboxes(Rect::new(0, 0, 10, 5), 3, 1);
+        assert_eq!(
+            rows,
+            [
+                Rect::new(0, 0, 10, 1),
+                Rect::new(0, 2, 10, 1),
+                Rect::new(0, 4, 10, 1),
+            ]
+        );
+    }
+
+    #[test]
+    fn stack_checkboxes_stays_within_area() {
+        let area = Rect::new(0, 3, 10, 4);
+        let rows = stack_checkboxes(area, 10, 1);
+        assert_eq!(rows.len(), 2);
+        assert!(rows.iter().all(|row| area.intersection(*row) == *row));
+        assert!(stack_checkboxes(Rect::new(0, 0, 10, 0), 3, 0).is_empty());
+    }
+
+    #[test]
+    fn wrap_line_keeps_word_across_spans() {
+        let line = Line::from(vec!["foo".red(), "bar".blue(), " baz".into()]);
+        let lines = wrap_line(&line, 6, WrapMode::WordThenChar);
+
+        assert_eq!(
+            lines,
+            vec![
+                Line::from(vec![
+                    Span::styled("foo", Style::default().fg(Color::Red)),
+                    Span::styled("bar", Style::default().fg(Color::Blue)),
+                ]),
+                Line::from("baz"),
+            ]
+        );
+    }
+
+    #[test]
+    fn wrap_line_modes() {
+        let line = Line::from("ab abcdefg");
+        let wrap = |mode| wrap_line(&line, 4, mode);
+
+        assert_eq!(
+            wrap(WrapMode::Word),
+            [Line::from("ab"), Line::from("abcdefg")]
+        );
+        assert_eq!(
+            wrap(WrapMode::WordThenChar),
+            [Line::from("ab"), Line::from("abcd"), Line::from("efg")]
+        );
+        assert_eq!(
+            wrap(WrapMode::Char),
+            [Line::from("ab a"), Line::from("bcde"), Line::from("fg")]
+        );
+
+        // Wide glyphs are never split across lines
+        let line = Line::from("日本語のテキスト");
+        for wrapped in wrap_line(&line, 5, WrapMode::Char) {
+            assert!(wrapped.width() <= 5);
+        }
+    }
+
+    #[test]
+    fn wrap_line_wide_glyphs_fit_max_width() {
+        let line = Line::from(vec![
+            Span::raw("Save 保存したファイル "),
+            Span::styled("✅ done", Style::default().fg(Color::Green)),
+            Span::raw(" and 日本語のテキスト"),
+        ]);
+        for max_width in 2..=24 {
+            let lines = wrap_line(&line, max_width, WrapMode::WordThenChar);
+            for wrapped in &lines {
+                assert!(
+                    wrapped.width() <= usize::from(max_width),
+                    "{wrapped:?} is wider than {max_width}"
+                );
+            }
+            let text: String = lines
+                .iter()
+                .flat_map(|l| l.spans.iter().map(|s| s.content.replace(' ', "")))
+                .collect();
+            assert_eq!(text, "Save保存したファイル✅doneand日本語のテキスト");
+        }
+    }
+
+    #[test]
+    fn wrap_line_empty() {
+        assert_eq!(
+            wrap_line(&Line::default(), 10, WrapMode::WordThenChar),
+            [Line::default()]
+        );
+        assert_eq!(
+            wrap_line(&Line::from(""), 10, WrapMode::Char),
+            [Line::from("")]
+        );
+    }
+
+    #[test]
+    fn wrap_line_single_long_word() {
+        let line = Line::from("abcdefghij");
+        assert_eq!(
+            wrap_line(&line, 4, WrapMode::WordThenChar),
+            [Line::from("abcd"), Line::from("efgh"), Line::from("ij")]
+        );
+        assert_eq!(
+            wrap_line(&line, 4, WrapMode::Word),
+            [Line::from("abcdefghij")]
+        );
+    }
+
+    #[test]
+    fn wrap_line_keeps_styles_across_split() {
+        let line = Line::from(vec!["red words".red(), " blue".blue()]);
+        assert_eq!(
+            wrap_line(&line, 5, WrapMode::WordThenChar),
+            [
+                Line::from(vec![Span::styled("red", Style::new().fg(Color::Red))]),
+                Line::from(vec![Span::styled("words", Style::new().fg(Color::Red))]),
+                Line::from(vec![Span::styled("blue", Style::new().fg(Color::Blue))]),
+            ]
+        );
+        assert_eq!(
+            wrap_line(&line, 7, WrapMode::Char),
+            [
+                Line::from(vec![Span::styled("red wor", Style::new().fg(Color::Red))]),
+                Line::from(vec![
+                    Span::styled("ds", Style::new().fg(Color::Red)),
+                    Span::styled(" blue", Style::new().fg(Color::Blue)),
+                ]),
+            ]
+        );
+    }
+
+    #[test]
+    fn wrap_line_keeps_grapheme_clusters() {
+        let family = "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}";
+        let line = Line::from(format!("{family}{family}e\u{301}"));
+        let lines = wrap_line(&line, 2, WrapMode::WordThenChar);
+        let text: Vec<String> = lines.iter().map(ToString::to_string).collect();
+        assert_eq!(text, [family, family, "e\u{301}"]);
+    }
+}
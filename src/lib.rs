@@ -50,15 +50,47 @@
 use std::borrow::Cow;
 
 use ratatui::buffer::Buffer;
-use ratatui::layout::Rect;
-use ratatui::style::{Style, Styled};
-use ratatui::text::{Line, Span};
+use ratatui::layout::{Margin, Position, Rect};
+use ratatui::style::{Color, Modifier, Style, Styled};
+use ratatui::text::{Line, Span, Text};
 use ratatui::widgets::{Block, Widget};
+use unicode_bidi::BidiInfo;
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
 
+mod cache;
+mod cycle;
+mod form;
+mod group;
+#[cfg(feature = "crossterm")]
+pub mod interaction;
+#[cfg(feature = "serde")]
+mod json;
+pub mod layout;
+#[cfg(feature = "serde")]
+mod serde_label;
+mod state;
 pub mod symbols;
+#[cfg(any(test, feature = "testing"))]
+pub mod testing;
+
+pub use cache::CachedCheckbox;
+use cache::WrapCache;
+pub use cycle::CycleBox;
+pub use form::{CheckboxForm, CheckboxFormState};
+pub use group::{CheckboxGroup, CheckboxGroupState, GroupMode};
+pub use state::CheckboxState;
+
+/// Number of trailing cells of a clipped label dimmed by [`Checkbox::fade_overflow`].
+const FADE_CELLS: u16 = 3;
 
 /// Position of the label relative to the checkbox symbol.
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "lowercase")
+)]
 pub enum LabelPosition {
     /// Label appears to the right of the checkbox (default)
     #[default]
@@ -73,6 +105,11 @@ pub enum LabelPosition {
 
 /// Horizontal alignment of content within its area.
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "lowercase")
+)]
 pub enum HorizontalAlignment {
     /// Align to the left (default)
     #[default]
@@ -85,6 +122,11 @@ pub enum HorizontalAlignment {
 
 /// Vertical alignment of content within its area.
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "lowercase")
+)]
 pub enum VerticalAlignment {
     /// Align to the top (default)
     #[default]
@@ -95,6 +137,163 @@ pub enum VerticalAlignment {
     Bottom,
 }
 
+/// What the checkbox symbol is aligned against when the label is above or below it.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "lowercase")
+)]
+pub enum SymbolAnchor {
+    /// Align the symbol within the whole area (default)
+    #[default]
+    AreaEdge,
+    /// Align the symbol within the columns taken by the label
+    LabelEdge,
+}
+
+/// How a wrapped label is broken into lines.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "lowercase")
+)]
+pub enum WrapMode {
+    /// Break between words only; a word wider than the line is clipped (default)
+    #[default]
+    Word,
+    /// Break at any character, ignoring word boundaries
+    Char,
+    /// Break between words, and inside words wider than the line
+    WordThenChar,
+}
+
+/// Letter case the label is shown in, set with [`Checkbox::label_transform`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "lowercase")
+)]
+pub enum LabelCase {
+    /// The label as written (default)
+    #[default]
+    None,
+    /// Every letter in uppercase
+    Upper,
+    /// Every letter in lowercase
+    Lower,
+    /// The first letter of each word in uppercase and the others in lowercase
+    Title,
+}
+
+/// State of a checkbox, used to look up its symbol with [`Checkbox::symbol_for`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "lowercase")
+)]
+pub enum CheckState {
+    /// Not checked (default)
+    #[default]
+    Unchecked,
+    /// Checked
+    Checked,
+    /// Neither checked nor unchecked, such as a parent of partially checked options
+    Indeterminate,
+}
+
+/// Region of the widget that the base [`Checkbox::style`] is painted over.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "lowercase")
+)]
+pub enum StyleScope {
+    /// The whole area, including the block borders and title (default)
+    #[default]
+    All,
+    /// The area inside the block, leaving its borders and title untouched
+    Inner,
+    /// Only the cells of the checkbox symbol and label
+    Content,
+}
+
+/// Role-based colors for a [`Checkbox`], with presets for dark and light terminals.
+///
+/// A color scheme is a higher-level alternative to setting individual styles: each color is
+/// applied to the role it names for the current state. Explicit styles such as
+/// [`Checkbox::checkbox_style`] and [`Checkbox::label_style`] are patched over the scheme colors.
+///
+/// # Examples
+///
+/// ```
+/// use tui_checkbox::{Checkbox, ColorScheme};
+///
+/// let checkbox = Checkbox::new("Option", true).color_scheme(ColorScheme::light());
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ColorScheme {
+    /// Foreground of the symbol when checked
+    pub checked_fg: Color,
+    /// Foreground of the symbol when unchecked
+    pub unchecked_fg: Color,
+    /// Foreground of the label
+    pub label_fg: Color,
+    /// Foreground of the symbol and label when disabled
+    pub disabled_fg: Color,
+    /// Background of the symbol and label when focused
+    pub focus_bg: Color,
+}
+
+impl ColorScheme {
+    /// Returns a color scheme suited to terminals with a dark background.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::style::Color;
+    /// use tui_checkbox::ColorScheme;
+    ///
+    /// assert_eq!(ColorScheme::dark().checked_fg, Color::Green);
+    /// ```
+    #[must_use]
+    pub const fn dark() -> Self {
+        Self {
+            checked_fg: Color::Green,
+            unchecked_fg: Color::Gray,
+            label_fg: Color::White,
+            disabled_fg: Color::DarkGray,
+            focus_bg: Color::DarkGray,
+        }
+    }
+
+    /// Returns a color scheme suited to terminals with a light background.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::style::Color;
+    /// use tui_checkbox::ColorScheme;
+    ///
+    /// assert_eq!(ColorScheme::light().label_fg, Color::Black);
+    /// ```
+    #[must_use]
+    pub const fn light() -> Self {
+        Self {
+            checked_fg: Color::Green,
+            unchecked_fg: Color::DarkGray,
+            label_fg: Color::Black,
+            disabled_fg: Color::Gray,
+            focus_bg: Color::Gray,
+        }
+    }
+}
+
 /// A widget that displays a checkbox with a label.
 ///
 /// A `Checkbox` can be in a checked or unchecked state. The checkbox is rendered with a symbol
@@ -104,8 +303,21 @@ pub enum VerticalAlignment {
 /// the label. You can also style just the checkbox symbol using [`Checkbox::checkbox_style`] or
 /// the label using [`Checkbox::label_style`].
 ///
+/// Styles are patched over each other in this order, so later ones win:
+///
+/// 1. [`Checkbox::style`], with any [`Checkbox::color_scheme`] and [`Checkbox::auto_color`]
+///    colors
+/// 2. [`Checkbox::checkbox_style`] for the symbol and [`Checkbox::label_style`] for the label
+/// 3. [`Checkbox::disabled_style`], when [disabled](Checkbox::disabled)
+/// 4. [`Checkbox::focus_style`] and then the current [`Checkbox::focus_style_frames`] style,
+///    when [focused](Checkbox::focused)
+///
 /// You can create a `Checkbox` using [`Checkbox::new`] or [`Checkbox::default`].
 ///
+/// With the `serde` feature, a `Checkbox` can be serialized to persist its configuration. The
+/// label is stored as plain text, the block and the right hint are not stored, and missing fields
+/// take their default values when deserializing.
+///
 /// # Examples
 ///
 /// ```
@@ -126,28 +338,83 @@ pub enum VerticalAlignment {
 /// Checkbox::new("Accept terms", false).block(Block::bordered().title("Settings"));
 /// ```
 #[expect(clippy::struct_field_names)] // checkbox_style needs to be differentiated from style
+#[expect(clippy::struct_excessive_bools)] // Each flag is an independent rendering option
 #[derive(Debug, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
 pub struct Checkbox<'a> {
     /// The label text displayed next to the checkbox
-    label: Line<'a>,
+    #[cfg_attr(feature = "serde", serde(with = "serde_label"))]
+    label: Text<'a>,
+    /// Value shown flush to the right edge of the label row
+    #[cfg_attr(feature = "serde", serde(skip))]
+    value: Option<Span<'a>>,
+    /// Secondary line shown below the label
+    #[cfg_attr(feature = "serde", serde(skip))]
+    description: Option<Line<'a>>,
+    /// Style of the description, patched over the base style
+    description_style: Style,
+    /// Whether the description is only shown while focused
+    description_on_focus: bool,
+    /// Character filling the gap between the label and its value
+    fill_char: char,
+    /// Number of spaces a tab in the label expands to
+    tab_width: u16,
+    /// Letter case the label is shown in
+    label_case: LabelCase,
     /// Whether the checkbox is checked
     checked: bool,
     /// Optional block to wrap the checkbox
+    #[cfg_attr(feature = "serde", serde(skip))]
     block: Option<Block<'a>>,
+    /// Style patched over the block around the content while checked
+    block_checked_style: Style,
     /// Base style for the entire widget
     style: Style,
     /// Style specifically for the checkbox symbol
     checkbox_style: Style,
     /// Style specifically for the label text
     label_style: Style,
+    /// Whether to cross out the label while checked
+    strikethrough_when_checked: bool,
     /// Symbol to use when checked
     checked_symbol: Cow<'a, str>,
+    /// Pre-styled content drawn in place of the checked symbol
+    #[cfg_attr(feature = "serde", serde(skip))]
+    checked_symbol_line: Option<Line<'a>>,
     /// Symbol to use when unchecked
     unchecked_symbol: Cow<'a, str>,
+    /// Whether the checkbox is in the indeterminate state
+    indeterminate: bool,
+    /// Symbol to use when indeterminate
+    indeterminate_symbol: Cow<'a, str>,
+    /// Whether a change of the checkbox is in flight
+    pending: bool,
+    /// Symbol to use when pending
+    pending_symbol: Cow<'a, str>,
     /// Position of the label relative to the checkbox
     label_position: LabelPosition,
+    /// Number of blank cells between the symbol and a label beside it
+    spacing: u16,
+    /// Text drawn between the symbol and a label beside it, replacing the spacing
+    separator: Option<Cow<'a, str>>,
     /// Horizontal alignment of the checkbox symbol
     horizontal_alignment: HorizontalAlignment,
+    /// Alignment of the symbol on its own, when it differs from the label
+    symbol_alignment: Option<HorizontalAlignment>,
+    /// Row of the symbol against a label beside it, when it differs from the default
+    symbol_vertical_alignment: Option<VerticalAlignment>,
+    /// Width of the column reserved for the symbol, if fixed
+    symbol_width: Option<u16>,
+    /// Text drawn right before and after the symbol
+    symbol_frame: Option<(Cow<'a, str>, Cow<'a, str>)>,
+    /// Style of the symbol frame, patched over the checkbox style
+    frame_style: Style,
+    /// What the symbol is aligned against when the label is above or below it
+    symbol_anchor: SymbolAnchor,
     /// Vertical alignment of the checkbox symbol
     vertical_alignment: VerticalAlignment,
     /// Minimum width constraint
@@ -156,21 +423,139 @@ pub struct Checkbox<'a> {
     max_width: Option<u16>,
     /// Whether to wrap label text to multiple lines
     wrap_label: bool,
+    /// How wrapped label lines are broken
+    wrap_mode: WrapMode,
+    /// Style patched onto wrapped label lines after the first
+    continuation_style: Style,
+    /// Whether the checkbox currently has keyboard focus
+    focused: bool,
+    /// Style patched over the symbol and label when focused
+    focus_style: Style,
+    /// Styles cycled through while focused, indexed by `frame`
+    focus_style_frames: Vec<Style>,
+    /// Animation frame used to pick from `focus_style_frames`
+    frame: usize,
+    /// Number of trailing label characters kept visible when truncating
+    truncate_suffix: Option<usize>,
+    /// Whether to dim the last cells of a clipped label
+    fade_overflow: bool,
+    /// Whether to draw the symbol and label on a shared pill highlight
+    pill: bool,
+    /// Style of the pill highlight
+    pill_style: Style,
+    /// Nested options rendered as indented checkboxes below the main one
+    sub_options: Vec<(String, bool)>,
+    /// Region the base style is painted over
+    style_scope: StyleScope,
+    /// Partial fill level in eighths (0-8), overriding the checked state symbol
+    partial: Option<u8>,
+    /// Number of frames the check animation takes
+    check_animation: Option<u16>,
+    /// Current frame of the check animation, if it is running
+    animation_frame: Option<u16>,
+    /// Intermediate symbols shown by [`Checkbox::render_frame`], from unchecked to checked
+    transition_symbols: Vec<Cow<'a, str>>,
+    /// Role-based colors applied below the explicit styles
+    color_scheme: Option<ColorScheme>,
+    /// Whether to color the symbol green when checked and gray when unchecked
+    auto_color: bool,
+    /// Whether to render without colors, keeping only the modifiers
+    monochrome: bool,
+    /// Whether to reorder mixed-direction labels with the Unicode bidi algorithm
+    bidi: bool,
+    /// Whether the content rows respond to clicks and take the base style across their width
+    full_width_hit: bool,
+    /// Whether to lay out the checkbox for right-to-left text
+    rtl: bool,
+    /// Whether to mark focus with corner glyphs around the content
+    focus_corners: bool,
+    /// Whether to draw each label glyph over two cells
+    double_width_label: bool,
+    /// Fixed width of the content box in horizontal positions
+    content_width: Option<u16>,
+    /// Hint pinned to the right edge of the area, such as a keybinding
+    #[cfg_attr(feature = "serde", serde(skip))]
+    right_hint: Option<Span<'a>>,
+    /// Decoration drawn right before the symbol and label
+    #[cfg_attr(feature = "serde", serde(skip))]
+    prefix: Option<Span<'a>>,
+    /// Decoration drawn right after the symbol and label
+    #[cfg_attr(feature = "serde", serde(skip))]
+    suffix: Option<Span<'a>>,
+    /// Placeholder tokens in the label and the icons that replace them
+    #[cfg_attr(feature = "serde", serde(skip))]
+    inline_icons: Vec<(String, Span<'a>)>,
+    /// Whether the symbol replaces the marker in the label
+    inline_symbol: bool,
+    /// Accelerator key underlined in the label
+    mnemonic: Option<char>,
+    /// Number of leading label rows scrolled out of view
+    scroll: u16,
+    /// Text announced by assistive technology in place of the label
+    accessible_label: Option<String>,
+    /// Nesting depth of the checkbox in a tree
+    indent: u16,
+    /// Whether to draw box-drawing guides in the indentation
+    tree_guides: bool,
+    /// Style of the tree guides
+    guide_style: Style,
+    /// Whether to mark a minimum width that does not fit the area
+    clip_indicator: bool,
+    /// Whether the checkbox is unavailable for interaction
+    disabled: bool,
+    /// Style patched over the symbol and label when disabled, if not the default
+    disabled_style: Option<Style>,
+    /// Whether the checkbox must be checked for its form to be valid
+    required: bool,
+    /// Style of the marker shown before the label while required and unchecked
+    required_style: Style,
 }
 
 impl Default for Checkbox<'_> {
     /// Returns a default `Checkbox` widget.
     ///
     /// The default widget has:
-    /// - Empty label
+    /// - Empty label, with no value and a value gap filled with spaces
+    /// - No description, in dark gray when set and shown whether focused or not
+    /// - Tabs in the label expanded to four spaces
+    /// - Label shown in the case it is written in
     /// - Unchecked state
-    /// - No block
-    /// - Default style for all elements
-    /// - Unicode checkbox symbols (☐ and ☑)
-    /// - Label position on the right
-    /// - Left and top alignment
+    /// - No block, left as is when checked
+    /// - Default style for all elements, with the label not crossed out when checked
+    /// - Unicode checkbox symbols (☐, ☑ and ▣ when indeterminate), not pre-styled
+    /// - Not indeterminate
+    /// - Not pending, shown as … when pending
+    /// - Label position on the right, one cell from the symbol
+    /// - Left and top alignment, with the symbol aligned along with the label within the area
+    /// - Symbol on the first row of a wrapped label, and centered against a multi-line label
+    /// - Symbol column as wide as the symbol, with no frame around it
     /// - No width constraints
-    /// - No label wrapping
+    /// - No label wrapping, breaking between words only when enabled, with continuation lines
+    ///   styled like the first
+    /// - Not focused, underlined when focused, with no focus animation frames
+    /// - No label truncation and no overflow fade
+    /// - No pill highlight
+    /// - No sub-options
+    /// - Base style painted over the whole area
+    /// - No partial fill
+    /// - No check animation and no transition symbols
+    /// - No color scheme and no automatic symbol colors
+    /// - Rendered in color
+    /// - No bidi reordering
+    /// - Left-to-right layout
+    /// - No focus corners
+    /// - Single width label
+    /// - Content sized to the symbol and label
+    /// - No right hint, prefix or suffix
+    /// - No inline icons, with the symbol beside the label
+    /// - No mnemonic
+    /// - No scroll offset
+    /// - No accessible label, so the label is announced
+    /// - No indentation, without tree guides and with an empty guide style
+    /// - No clip indicator
+    /// - Hit area limited to the symbol and label
+    /// - Enabled, dimmed in dark gray when disabled
+    /// - Not required, with a red marker when required
     ///
     /// # Examples
     ///
@@ -181,20 +566,83 @@ impl Default for Checkbox<'_> {
     /// ```
     fn default() -> Self {
         Self {
-            label: Line::default(),
+            label: Text::from(Line::default()),
+            value: None,
+            description: None,
+            description_style: Style::new().fg(Color::DarkGray),
+            description_on_focus: false,
+            fill_char: ' ',
+            tab_width: 4,
+            label_case: LabelCase::None,
             checked: false,
             block: None,
+            block_checked_style: Style::default(),
             style: Style::default(),
             checkbox_style: Style::default(),
             label_style: Style::default(),
+            strikethrough_when_checked: false,
             checked_symbol: Cow::Borrowed(symbols::CHECKED),
+            checked_symbol_line: None,
             unchecked_symbol: Cow::Borrowed(symbols::UNCHECKED),
+            indeterminate: false,
+            indeterminate_symbol: Cow::Borrowed(symbols::INDETERMINATE),
+            pending: false,
+            pending_symbol: Cow::Borrowed(symbols::PENDING),
             label_position: LabelPosition::default(),
+            spacing: 1,
+            separator: None,
             horizontal_alignment: HorizontalAlignment::default(),
+            symbol_alignment: None,
+            symbol_vertical_alignment: None,
+            symbol_width: None,
+            symbol_frame: None,
+            frame_style: Style::default(),
+            symbol_anchor: SymbolAnchor::AreaEdge,
             vertical_alignment: VerticalAlignment::default(),
             min_width: None,
             max_width: None,
             wrap_label: false,
+            wrap_mode: WrapMode::Word,
+            continuation_style: Style::new(),
+            focused: false,
+            focus_style: Style::new().add_modifier(Modifier::UNDERLINED),
+            focus_style_frames: Vec::new(),
+            frame: 0,
+            truncate_suffix: None,
+            fade_overflow: false,
+            pill: false,
+            pill_style: Style::default(),
+            sub_options: Vec::new(),
+            style_scope: StyleScope::default(),
+            partial: None,
+            check_animation: None,
+            animation_frame: None,
+            transition_symbols: Vec::new(),
+            color_scheme: None,
+            auto_color: false,
+            monochrome: false,
+            bidi: false,
+            full_width_hit: false,
+            rtl: false,
+            focus_corners: false,
+            double_width_label: false,
+            content_width: None,
+            right_hint: None,
+            prefix: None,
+            suffix: None,
+            inline_icons: Vec::new(),
+            inline_symbol: false,
+            mnemonic: None,
+            scroll: 0,
+            accessible_label: None,
+            indent: 0,
+            tree_guides: false,
+            guide_style: Style::new(),
+            clip_indicator: false,
+            disabled: false,
+            disabled_style: None,
+            required: false,
+            required_style: Style::new().fg(Color::Red),
         }
     }
 }
@@ -222,12 +670,70 @@ impl<'a> Checkbox<'a> {
         T: Into<Line<'a>>,
     {
         Self {
-            label: label.into(),
+            label: Text::from(label.into()),
             checked,
             ..Default::default()
         }
     }
 
+    /// Creates a new checkbox with ASCII symbols (`[X]`, `[ ]` and `[-]`).
+    ///
+    /// This is a shorthand for [`Checkbox::new`] followed by [`Checkbox::symbols`] with
+    /// [`SymbolSet::ascii`].
+    ///
+    /// [`SymbolSet::ascii`]: symbols::SymbolSet::ascii
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tui_checkbox::Checkbox;
+    ///
+    /// let checkbox = Checkbox::ascii("Enable feature", true);
+    /// ```
+    pub fn ascii<T>(label: T, checked: bool) -> Self
+    where
+        T: Into<Line<'a>>,
+    {
+        Self::new(label, checked).symbols(symbols::SymbolSet::ascii())
+    }
+
+    /// Creates a new checkbox with the default Unicode symbols (☑, ☐ and ▣).
+    ///
+    /// This is the same as [`Checkbox::new`], spelled out for code that picks a symbol set per
+    /// checkbox.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tui_checkbox::Checkbox;
+    ///
+    /// let checkbox = Checkbox::unicode("Enable feature", true);
+    /// ```
+    pub fn unicode<T>(label: T, checked: bool) -> Self
+    where
+        T: Into<Line<'a>>,
+    {
+        Self::new(label, checked).symbols(symbols::SymbolSet::unicode())
+    }
+
+    /// Creates a new checkbox with emoji symbols (✅ and ⬜).
+    ///
+    /// The emoji take two cells in most terminals, which the layout accounts for.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tui_checkbox::Checkbox;
+    ///
+    /// let checkbox = Checkbox::emoji("Enable feature", true);
+    /// ```
+    pub fn emoji<T>(label: T, checked: bool) -> Self
+    where
+        T: Into<Line<'a>>,
+    {
+        Self::new(label, checked).symbols(symbols::SymbolSet::emoji())
+    }
+
     /// Sets the label of the checkbox.
     ///
     /// The label can be any type that converts into a [`Line`], such as a string or a styled span.
@@ -244,881 +750,6646 @@ impl<'a> Checkbox<'a> {
     where
         T: Into<Line<'a>>,
     {
-        self.label = label.into();
+        self.label = Text::from(label.into());
         self
     }
 
-    /// Sets the checked state of the checkbox.
+    /// Sets a label made of a key at the start of the row and a value flush to its right edge.
+    ///
+    /// The value ends at the right edge of the space available to the label, and the gap between
+    /// the key and the value is filled with the [`Checkbox::fill_char`], as in settings screens.
+    /// The label takes the whole width, so the checkbox grows to the available width. A wrapped
+    /// or truncated key is laid out in the space left of the value, which stays on the first row.
     ///
     /// # Examples
     ///
     /// ```
     /// use tui_checkbox::Checkbox;
     ///
-    /// let checkbox = Checkbox::default().checked(true);
+    /// // Renders as "☑ Theme.....Dark" in 16 columns
+    /// let checkbox = Checkbox::default()
+    ///     .checked(true)
+    ///     .label_value("Theme", "Dark")
+    ///     .fill_char('.');
     /// ```
     #[must_use = "method moves the value of self and returns the modified value"]
-    pub const fn checked(mut self, checked: bool) -> Self {
-        self.checked = checked;
+    pub fn label_value<K, V>(mut self, key: K, value: V) -> Self
+    where
+        K: Into<Line<'a>>,
+        V: Into<Span<'a>>,
+    {
+        self.label = Text::from(key.into());
+        self.value = Some(value.into());
         self
     }
 
-    /// Wraps the checkbox with the given block.
+    /// Sets the character filling the gap between the key and the value of a
+    /// [`Checkbox::label_value`].
+    ///
+    /// The fill takes the label style. The default is a space.
     ///
     /// # Examples
     ///
     /// ```
-    /// use ratatui::widgets::Block;
     /// use tui_checkbox::Checkbox;
     ///
-    /// let checkbox = Checkbox::new("Option", false).block(Block::bordered().title("Settings"));
+    /// let checkbox = Checkbox::default().label_value("Theme", "Dark").fill_char('·');
     /// ```
     #[must_use = "method moves the value of self and returns the modified value"]
-    pub fn block(mut self, block: Block<'a>) -> Self {
-        self.block = Some(block);
+    pub const fn fill_char(mut self, fill: char) -> Self {
+        self.fill_char = fill;
         self
     }
 
-    /// Sets the base style of the widget.
-    ///
-    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
-    /// your own type that implements [`Into<Style>`]).
+    /// Sets a secondary line shown below the label, such as a muted explanation in a settings
+    /// row.
     ///
-    /// This style will be applied to both the checkbox symbol and the label unless overridden by
-    /// more specific styles.
+    /// The description starts in the column of the label rather than under the symbol, and adds
+    /// its rows to the height of the checkbox. It is wrapped along with the label when
+    /// [`Checkbox::wrap_label`] is enabled, and truncated like the label otherwise.
     ///
     /// # Examples
     ///
     /// ```
-    /// use ratatui::style::{Color, Style};
     /// use tui_checkbox::Checkbox;
     ///
-    /// let checkbox = Checkbox::new("Option", false).style(Style::default().fg(Color::White));
+    /// let checkbox =
+    ///     Checkbox::new("Auto-save", true).description("Save files when focus changes");
+    /// assert_eq!(checkbox.measure(40), (31, 2));
     /// ```
-    ///
-    /// [`Color`]: ratatui::style::Color
     #[must_use = "method moves the value of self and returns the modified value"]
-    pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
-        self.style = style.into();
+    pub fn description<T>(mut self, description: T) -> Self
+    where
+        T: Into<Line<'a>>,
+    {
+        self.description = Some(description.into());
         self
     }
 
-    /// Sets the style of the checkbox symbol.
-    ///
-    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
-    /// your own type that implements [`Into<Style>`]).
+    /// Sets the style of the [`Checkbox::description`], patched over the base style.
     ///
-    /// This style will be combined with the base style set by [`Checkbox::style`].
+    /// The default is a dark gray foreground.
     ///
     /// # Examples
     ///
     /// ```
-    /// use ratatui::style::{Color, Style};
+    /// use ratatui::style::{Style, Stylize};
     /// use tui_checkbox::Checkbox;
     ///
-    /// let checkbox = Checkbox::new("Option", true).checkbox_style(Style::default().fg(Color::Green));
+    /// let checkbox = Checkbox::new("Auto-save", true)
+    ///     .description("Save files when focus changes")
+    ///     .description_style(Style::new().italic());
     /// ```
-    ///
-    /// [`Color`]: ratatui::style::Color
     #[must_use = "method moves the value of self and returns the modified value"]
-    pub fn checkbox_style<S: Into<Style>>(mut self, style: S) -> Self {
-        self.checkbox_style = style.into();
+    pub fn description_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.description_style = style.into();
         self
     }
 
-    /// Sets the style of the label text.
+    /// Sets whether the [`Checkbox::description`] is only shown while the checkbox is
+    /// [focused](Checkbox::focused), like a tooltip.
     ///
-    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
-    /// your own type that implements [`Into<Style>`]).
-    ///
-    /// This style will be combined with the base style set by [`Checkbox::style`].
+    /// An unfocused checkbox then takes no rows for its description, keeping dense lists compact.
+    /// The default is `false`.
     ///
     /// # Examples
     ///
     /// ```
-    /// use ratatui::style::{Color, Style};
     /// use tui_checkbox::Checkbox;
     ///
-    /// let checkbox = Checkbox::new("Option", false).label_style(Style::default().fg(Color::Gray));
+    /// let checkbox = Checkbox::new("Auto-save", true)
+    ///     .description("Save files when focus changes")
+    ///     .description_on_focus(true);
+    /// assert_eq!(checkbox.measure(40).1, 1);
+    /// assert_eq!(checkbox.focused(true).measure(40).1, 2);
     /// ```
-    ///
-    /// [`Color`]: ratatui::style::Color
     #[must_use = "method moves the value of self and returns the modified value"]
-    pub fn label_style<S: Into<Style>>(mut self, style: S) -> Self {
-        self.label_style = style.into();
+    pub const fn description_on_focus(mut self, on_focus: bool) -> Self {
+        self.description_on_focus = on_focus;
         self
     }
 
-    /// Sets the symbol to use when the checkbox is checked.
+    /// Sets the number of spaces each tab in the label expands to.
     ///
-    /// The default is `☑` (U+2611).
+    /// Tabs are expanded and other control characters are dropped before the label is laid out,
+    /// since a terminal cell cannot show them. `0` drops tabs as well. The default is `4`.
     ///
     /// # Examples
     ///
     /// ```
     /// use tui_checkbox::Checkbox;
     ///
-    /// let checkbox = Checkbox::new("Option", true).checked_symbol("[X]");
+    /// let checkbox = Checkbox::new("Name:\tValue", false).tab_width(2);
+    /// assert_eq!(checkbox.to_plain_string(), "☐ Name:  Value");
     /// ```
     #[must_use = "method moves the value of self and returns the modified value"]
-    pub fn checked_symbol<T>(mut self, symbol: T) -> Self
-    where
-        T: Into<Cow<'a, str>>,
-    {
-        self.checked_symbol = symbol.into();
+    pub const fn tab_width(mut self, width: u16) -> Self {
+        self.tab_width = width;
         self
     }
 
-    /// Sets the symbol to use when the checkbox is unchecked.
+    /// Sets the letter case the label is shown in, such as uppercase for headers.
     ///
-    /// The default is `☐` (U+2610).
+    /// The case is changed when the label is rendered, span by span so each keeps its style,
+    /// while the stored label stays as written. The value and description keep their case.
     ///
     /// # Examples
     ///
     /// ```
-    /// use tui_checkbox::Checkbox;
+    /// use tui_checkbox::{Checkbox, LabelCase};
     ///
-    /// let checkbox = Checkbox::new("Option", false).unchecked_symbol("[ ]");
+    /// let checkbox = Checkbox::new("Advanced options", false).label_transform(LabelCase::Upper);
+    /// assert_eq!(checkbox.to_plain_string(), "☐ ADVANCED OPTIONS");
     /// ```
     #[must_use = "method moves the value of self and returns the modified value"]
-    pub fn unchecked_symbol<T>(mut self, symbol: T) -> Self
-    where
-        T: Into<Cow<'a, str>>,
-    {
-        self.unchecked_symbol = symbol.into();
+    pub const fn label_transform(mut self, case: LabelCase) -> Self {
+        self.label_case = case;
         self
     }
 
-    /// Sets the position of the label relative to the checkbox symbol.
+    /// Sets a label that spans several lines.
     ///
-    /// The default is [`LabelPosition::Right`].
+    /// Each line of the text is laid out on its own row, and strings are split on `\n`. In the
+    /// [`LabelPosition::Left`] and [`LabelPosition::Right`] positions the symbol is vertically
+    /// centered against the block of label lines.
     ///
     /// # Examples
     ///
     /// ```
-    /// use tui_checkbox::{Checkbox, LabelPosition};
+    /// use tui_checkbox::Checkbox;
     ///
-    /// let checkbox = Checkbox::new("Option", false).label_position(LabelPosition::Left);
+    /// let checkbox = Checkbox::default().label_text("Auto-save\nSaves every five minutes");
     /// ```
     #[must_use = "method moves the value of self and returns the modified value"]
-    pub const fn label_position(mut self, position: LabelPosition) -> Self {
-        self.label_position = position;
+    pub fn label_text<T>(mut self, label: T) -> Self
+    where
+        T: Into<Text<'a>>,
+    {
+        self.label = label.into();
         self
     }
 
-    /// Sets the horizontal alignment of the checkbox content within its area.
+    /// Replaces each occurrence of `token` in the label with `icon` when rendering.
     ///
-    /// The default is [`HorizontalAlignment::Left`].
+    /// This keeps the label a template such as `"Save {icon} changes"` while the icon is a styled
+    /// glyph. The icon style is patched over the style of the text the token appears in. Calling
+    /// this again with another token adds another icon.
     ///
     /// # Examples
     ///
     /// ```
-    /// use tui_checkbox::{Checkbox, HorizontalAlignment};
+    /// use ratatui::style::Stylize;
+    /// use tui_checkbox::Checkbox;
     ///
-    /// let checkbox = Checkbox::new("Option", false)
-    ///     .horizontal_alignment(HorizontalAlignment::Center);
+    /// // Renders as "☐ Save 💾 changes" with a blue icon
+    /// let checkbox =
+    ///     Checkbox::new("Save {icon} changes", false).with_inline_icon("{icon}", "💾".blue());
     /// ```
     #[must_use = "method moves the value of self and returns the modified value"]
-    pub const fn horizontal_alignment(mut self, alignment: HorizontalAlignment) -> Self {
-        self.horizontal_alignment = alignment;
+    pub fn with_inline_icon<T>(mut self, token: &str, icon: T) -> Self
+    where
+        T: Into<Span<'a>>,
+    {
+        self.inline_icons.push((token.to_string(), icon.into()));
         self
     }
 
-    /// Sets the vertical alignment of the checkbox content within its area.
+    /// Sets whether the symbol is drawn in place of the `{}` marker in the label.
     ///
-    /// The default is [`VerticalAlignment::Top`].
+    /// When enabled and the label contains [`symbols::INLINE_MARKER`], its first occurrence is
+    /// replaced by the current symbol in the checkbox style, so the box can sit in the middle of
+    /// the text. [`Checkbox::label_position`], the spacing and the separator are then ignored and
+    /// the label takes the whole width. A label without the marker keeps the symbol beside it.
+    /// The marker has to be within a single span of the label.
     ///
     /// # Examples
     ///
     /// ```
-    /// use tui_checkbox::{Checkbox, VerticalAlignment};
+    /// use tui_checkbox::Checkbox;
     ///
-    /// let checkbox = Checkbox::new("Option", false)
-    ///     .vertical_alignment(VerticalAlignment::Center);
+    /// let checkbox = Checkbox::new("Enable {} advanced mode", true).inline_symbol(true);
+    /// assert_eq!(checkbox.to_plain_string(), "Enable ☑ advanced mode");
     /// ```
     #[must_use = "method moves the value of self and returns the modified value"]
-    pub const fn vertical_alignment(mut self, alignment: VerticalAlignment) -> Self {
-        self.vertical_alignment = alignment;
+    pub const fn inline_symbol(mut self, inline_symbol: bool) -> Self {
+        self.inline_symbol = inline_symbol;
         self
     }
 
-    /// Sets the minimum width constraint for the checkbox widget.
+    /// Sets the accelerator key of the checkbox, underlined where it first appears in the label.
     ///
-    /// The default is no minimum width.
+    /// The first character of the label matching `key`, ignoring case, is rendered with
+    /// [`Modifier::UNDERLINED`], as `&File` marks `F` in GUI menus. When the label doesn't contain
+    /// the key nothing is underlined and [`Checkbox::mnemonic_key`] returns `None`.
     ///
     /// # Examples
     ///
     /// ```
     /// use tui_checkbox::Checkbox;
     ///
-    /// let checkbox = Checkbox::new("Option", false).min_width(20);
+    /// // Underlines the "S" of "Save"
+    /// let checkbox = Checkbox::new("Save on exit", false).mnemonic('s');
+    /// assert_eq!(checkbox.mnemonic_key(), Some('s'));
     /// ```
     #[must_use = "method moves the value of self and returns the modified value"]
-    pub const fn min_width(mut self, width: u16) -> Self {
-        self.min_width = Some(width);
+    pub const fn mnemonic(mut self, key: char) -> Self {
+        self.mnemonic = Some(key);
         self
     }
 
-    /// Sets the maximum width constraint for the checkbox widget.
+    /// Returns the key that activates the checkbox, set by [`Checkbox::mnemonic`].
+    ///
+    /// Returns `None` when no mnemonic is set or the label doesn't contain it, so an event loop
+    /// can map key presses to toggles without matching keys that are not shown.
+    #[must_use]
+    pub fn mnemonic_key(&self) -> Option<char> {
+        self.mnemonic.filter(|&key| {
+            self.label
+                .lines
+                .iter()
+                .flat_map(|line| &line.spans)
+                .any(|span| span.content.chars().any(|c| chars_match(c, key)))
+        })
+    }
+
+    /// Sets the text announced for the checkbox by assistive technology instead of the label.
     ///
-    /// The default is no maximum width.
+    /// Use it when the label carries decoration, such as icons or placeholder tokens, that
+    /// should not be read out. It doesn't affect rendering; see
+    /// [`Checkbox::accessible_description`].
     ///
     /// # Examples
     ///
     /// ```
     /// use tui_checkbox::Checkbox;
     ///
-    /// let checkbox = Checkbox::new("Option", false).max_width(40);
+    /// let checkbox = Checkbox::new("🔔 Alerts", true).accessible_label("Alerts");
+    /// assert_eq!(checkbox.accessible_description(), "checkbox, checked, Alerts");
     /// ```
     #[must_use = "method moves the value of self and returns the modified value"]
-    pub const fn max_width(mut self, width: u16) -> Self {
-        self.max_width = Some(width);
+    pub fn accessible_label<T: Into<String>>(mut self, label: T) -> Self {
+        self.accessible_label = Some(label.into());
         self
     }
 
-    /// Enables or disables label text wrapping.
+    /// Scrolls the content of the checkbox up by `rows` rows.
     ///
-    /// When enabled, the label will wrap to multiple lines if it exceeds the available width.
-    /// The default is `false` (no wrapping).
+    /// The first `rows` rows of the laid out checkbox, usually the leading lines of a wrapped
+    /// label, are skipped and the rest are rendered from the top of the area, so a label taller
+    /// than the area can be paged through. The symbol scrolls out with its row. Scrolling past the
+    /// end renders nothing.
     ///
     /// # Examples
     ///
     /// ```
     /// use tui_checkbox::Checkbox;
     ///
-    /// let checkbox = Checkbox::new("This is a very long label that should wrap", false)
+    /// // Shows the label from its second wrapped line on
+    /// let checkbox = Checkbox::new("A label that wraps over several lines", false)
     ///     .wrap_label(true)
-    ///     .max_width(30);
+    ///     .scroll(1);
     /// ```
     #[must_use = "method moves the value of self and returns the modified value"]
-    pub const fn wrap_label(mut self, wrap: bool) -> Self {
-        self.wrap_label = wrap;
+    pub const fn scroll(mut self, rows: u16) -> Self {
+        self.scroll = rows;
         self
     }
-}
-
-impl Styled for Checkbox<'_> {
-    type Item = Self;
 
-    fn style(&self) -> Style {
-        self.style
+    /// Sets the checked state of the checkbox.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tui_checkbox::Checkbox;
+    ///
+    /// let checkbox = Checkbox::default().checked(true);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn checked(mut self, checked: bool) -> Self {
+        self.checked = checked;
+        self
     }
 
-    fn set_style<S: Into<Style>>(mut self, style: S) -> Self::Item {
-        self.style = style.into();
-        self
+    /// Returns whether the checkbox is checked.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tui_checkbox::Checkbox;
+    ///
+    /// assert!(Checkbox::new("Option", true).is_checked());
+    /// ```
+    #[must_use]
+    pub const fn is_checked(&self) -> bool {
+        self.checked
     }
-}
 
-impl Widget for Checkbox<'_> {
-    fn render(self, area: Rect, buf: &mut Buffer) {
-        Widget::render(&self, area, buf);
+    /// Returns whether the checkbox is valid, which a [required](Checkbox::required) checkbox
+    /// only is when checked.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tui_checkbox::Checkbox;
+    ///
+    /// assert!(Checkbox::new("Subscribe", false).is_valid());
+    /// assert!(!Checkbox::new("Accept terms", false).required(true).is_valid());
+    /// assert!(Checkbox::new("Accept terms", true).required(true).is_valid());
+    /// ```
+    #[must_use]
+    pub const fn is_valid(&self) -> bool {
+        !self.required || self.checked
     }
-}
 
-impl Widget for &Checkbox<'_> {
-    fn render(self, area: Rect, buf: &mut Buffer) {
-        buf.set_style(area, self.style);
-        let inner = if let Some(ref block) = self.block {
+    /// Sets the checked state of the checkbox in place, for a checkbox kept in the model.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tui_checkbox::Checkbox;
+    ///
+    /// let mut checkbox = Checkbox::new("Option", false);
+    /// checkbox.set_checked(true);
+    /// assert!(checkbox.is_checked());
+    /// ```
+    pub fn set_checked(&mut self, checked: bool) {
+        self.checked = checked;
+    }
+
+    /// Flips the checked state of the checkbox in place.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tui_checkbox::Checkbox;
+    ///
+    /// let mut checkbox = Checkbox::new("Option", false);
+    /// checkbox.toggle();
+    /// assert!(checkbox.is_checked());
+    /// ```
+    pub fn toggle(&mut self) {
+        self.checked = !self.checked;
+    }
+
+    /// Returns whether `other` shows the same content, ignoring how it is styled and laid out.
+    ///
+    /// Unlike `==`, this only compares the text of the label, value and description, the checked,
+    /// indeterminate and pending states and the symbols. Styles, the block, focus, alignment and other
+    /// layout options are ignored, so two checkboxes that differ only by a transient focus style
+    /// are considered the same, and a label split into differently styled spans equals the same
+    /// text in a single span.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::style::{Color, Style};
+    /// use tui_checkbox::Checkbox;
+    ///
+    /// let checkbox = Checkbox::new("Option", true);
+    /// let focused = checkbox.clone().focused(true).style(Style::default().bg(Color::Blue));
+    /// assert!(checkbox.content_eq(&focused));
+    /// assert_ne!(checkbox, focused);
+    /// ```
+    #[must_use]
+    pub fn content_eq(&self, other: &Self) -> bool {
+        let text = |line: Option<&Line>| line.map(ToString::to_string);
+        self.checked == other.checked
+            && self.indeterminate == other.indeterminate
+            && self.pending == other.pending
+            && self.checked_symbol == other.checked_symbol
+            && self.unchecked_symbol == other.unchecked_symbol
+            && self.indeterminate_symbol == other.indeterminate_symbol
+            && self.pending_symbol == other.pending_symbol
+            && self.label.to_string() == other.label.to_string()
+            && self.value.as_ref().map(|value| &value.content)
+                == other.value.as_ref().map(|value| &value.content)
+            && text(self.description.as_ref()) == text(other.description.as_ref())
+    }
+
+    /// Wraps the checkbox with the given block.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::widgets::Block;
+    /// use tui_checkbox::Checkbox;
+    ///
+    /// let checkbox = Checkbox::new("Option", false).block(Block::bordered().title("Settings"));
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = Some(block);
+        self
+    }
+
+    /// Sets the style patched over the [`Checkbox::block`] while the checkbox is checked.
+    ///
+    /// The style is patched over the cells of the block around the content, its borders, title
+    /// and padding, after the block is drawn with its own styles, so a checked checkbox can have
+    /// a green border without rebuilding the block. It has no effect without a block.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::style::{Color, Style};
+    /// use ratatui::widgets::Block;
+    /// use tui_checkbox::Checkbox;
+    ///
+    /// let checkbox = Checkbox::new("Option", true)
+    ///     .block(Block::bordered())
+    ///     .block_checked_style(Style::default().fg(Color::Green));
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn block_checked_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.block_checked_style = style.into();
+        self
+    }
+
+    /// Sets the base style of the widget.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// This style will be applied to both the checkbox symbol and the label unless overridden by
+    /// more specific styles.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::style::{Color, Style};
+    /// use tui_checkbox::Checkbox;
+    ///
+    /// let checkbox = Checkbox::new("Option", false).style(Style::default().fg(Color::White));
+    /// ```
+    ///
+    /// [`Color`]: ratatui::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets the style of the checkbox symbol.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// This style will be combined with the base style set by [`Checkbox::style`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::style::{Color, Style};
+    /// use tui_checkbox::Checkbox;
+    ///
+    /// let checkbox = Checkbox::new("Option", true).checkbox_style(Style::default().fg(Color::Green));
+    /// ```
+    ///
+    /// [`Color`]: ratatui::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn checkbox_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.checkbox_style = style.into();
+        self
+    }
+
+    /// Sets the style of the label text.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// This style will be combined with the base style set by [`Checkbox::style`]. Its background
+    /// also fills the gap between the symbol and the label, so a highlighted label stays
+    /// continuous up to the symbol.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::style::{Color, Style};
+    /// use tui_checkbox::Checkbox;
+    ///
+    /// let checkbox = Checkbox::new("Option", false).label_style(Style::default().fg(Color::Gray));
+    /// ```
+    ///
+    /// [`Color`]: ratatui::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn label_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.label_style = style.into();
+        self
+    }
+
+    /// Crosses out the label while the checkbox is checked, as in a todo list.
+    ///
+    /// When checked, [`Modifier::CROSSED_OUT`] is added on top of the label style; when unchecked
+    /// or indeterminate the label is unaffected. The default is `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tui_checkbox::Checkbox;
+    ///
+    /// let checkbox = Checkbox::new("Buy milk", true).strikethrough_when_checked(true);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn strikethrough_when_checked(mut self, strikethrough: bool) -> Self {
+        self.strikethrough_when_checked = strikethrough;
+        self
+    }
+
+    /// Sets the symbol to use when the checkbox is checked.
+    ///
+    /// The default is `☑` (U+2611).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tui_checkbox::Checkbox;
+    ///
+    /// let checkbox = Checkbox::new("Option", true).checked_symbol("[X]");
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn checked_symbol<T>(mut self, symbol: T) -> Self
+    where
+        T: Into<Cow<'a, str>>,
+    {
+        self.checked_symbol = symbol.into();
+        self.checked_symbol_line = None;
+        self
+    }
+
+    /// Sets a pre-styled span to use as the symbol when the checkbox is checked.
+    ///
+    /// See [`Checkbox::checked_symbol_line`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::style::Stylize;
+    /// use tui_checkbox::Checkbox;
+    ///
+    /// let checkbox = Checkbox::new("Option", true).checked_symbol_span("✔".green().bold());
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn checked_symbol_span<T>(self, symbol: T) -> Self
+    where
+        T: Into<Span<'a>>,
+    {
+        self.checked_symbol_line(symbol.into())
+    }
+
+    /// Sets a pre-styled line to use as the symbol when the checkbox is checked, such as a red
+    /// `X` between gray brackets.
+    ///
+    /// Each span keeps its own style, patched over the checkbox style, so the symbol can have
+    /// several colors. The text of the line becomes the [`Checkbox::checked_symbol`], which is
+    /// what [`Checkbox::symbol`] returns and what the layout measures.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::style::Stylize;
+    /// use ratatui::text::Line;
+    /// use tui_checkbox::Checkbox;
+    ///
+    /// let checkbox = Checkbox::new("Option", true)
+    ///     .checked_symbol_line(Line::from(vec!["[".gray(), "X".red(), "]".gray()]));
+    /// assert_eq!(checkbox.symbol(), "[X]");
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn checked_symbol_line<T>(mut self, symbol: T) -> Self
+    where
+        T: Into<Line<'a>>,
+    {
+        let symbol = symbol.into();
+        self.checked_symbol = symbol.to_string().into();
+        self.checked_symbol_line = Some(symbol);
+        self
+    }
+
+    /// Sets the symbol to use when the checkbox is unchecked.
+    ///
+    /// The default is `☐` (U+2610).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tui_checkbox::Checkbox;
+    ///
+    /// let checkbox = Checkbox::new("Option", false).unchecked_symbol("[ ]");
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn unchecked_symbol<T>(mut self, symbol: T) -> Self
+    where
+        T: Into<Cow<'a, str>>,
+    {
+        self.unchecked_symbol = symbol.into();
+        self
+    }
+
+    /// Sets the symbol to use when the checkbox is [indeterminate](Checkbox::indeterminate).
+    ///
+    /// The default is `▣` (U+25A3).
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn indeterminate_symbol<T>(mut self, symbol: T) -> Self
+    where
+        T: Into<Cow<'a, str>>,
+    {
+        self.indeterminate_symbol = symbol.into();
+        self
+    }
+
+    /// Sets the checked, unchecked and indeterminate symbols at once from a [`SymbolSet`].
+    ///
+    /// A set without an indeterminate symbol keeps the current one.
+    ///
+    /// [`SymbolSet`]: symbols::SymbolSet
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tui_checkbox::symbols::SymbolSet;
+    /// use tui_checkbox::Checkbox;
+    ///
+    /// let checkbox = Checkbox::new("Option", false).symbols(SymbolSet::ascii());
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn symbols(mut self, set: symbols::SymbolSet<'a>) -> Self {
+        self.checked_symbol = set.checked.into();
+        self.checked_symbol_line = None;
+        self.unchecked_symbol = set.unchecked.into();
+        if let Some(indeterminate) = set.indeterminate {
+            self.indeterminate_symbol = indeterminate.into();
+        }
+        self
+    }
+
+    /// Returns the symbol for the current state, as the checkbox would render it.
+    ///
+    /// This is the [`Checkbox::pending_symbol`] while pending, the fill of a
+    /// [`Checkbox::partial`] checkbox, and otherwise the symbol given by
+    /// [`Checkbox::symbol_for`]. Frames of a running [`Checkbox::check_animation`] are not
+    /// included.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tui_checkbox::Checkbox;
+    ///
+    /// assert_eq!(Checkbox::new("Option", true).symbol(), "☑");
+    /// assert_eq!(Checkbox::new("Option", true).indeterminate(true).symbol(), "▣");
+    /// ```
+    #[must_use]
+    pub fn symbol(&self) -> &str {
+        if self.pending {
+            return &self.pending_symbol;
+        }
+        match self.partial {
+            Some(eighths @ 1..=7) => symbols::PARTIAL_FILL[usize::from(eighths) - 1],
+            Some(0) => self.symbol_for(CheckState::Unchecked),
+            Some(_) => self.symbol_for(CheckState::Checked),
+            None if self.indeterminate => self.symbol_for(CheckState::Indeterminate),
+            None if self.checked => self.symbol_for(CheckState::Checked),
+            None => self.symbol_for(CheckState::Unchecked),
+        }
+    }
+
+    /// Returns the symbol the checkbox uses in `state`, regardless of its current state.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tui_checkbox::{CheckState, Checkbox};
+    ///
+    /// let checkbox = Checkbox::ascii("Option", false);
+    /// assert_eq!(checkbox.symbol_for(CheckState::Checked), "[X]");
+    /// ```
+    #[must_use]
+    pub fn symbol_for(&self, state: CheckState) -> &str {
+        match state {
+            CheckState::Unchecked => &self.unchecked_symbol,
+            CheckState::Checked => &self.checked_symbol,
+            CheckState::Indeterminate => &self.indeterminate_symbol,
+        }
+    }
+
+    /// Sets whether the checkbox is indeterminate, such as a parent whose children are only
+    /// partly checked.
+    ///
+    /// An indeterminate checkbox shows its [`Checkbox::indeterminate_symbol`] whatever its
+    /// checked state. The default is `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tui_checkbox::Checkbox;
+    ///
+    /// let checkbox = Checkbox::new("Select all", false).indeterminate(true);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn indeterminate(mut self, indeterminate: bool) -> Self {
+        self.indeterminate = indeterminate;
+        self
+    }
+
+    /// Sets whether a change of the checkbox is in flight, such as a toggle waiting for a server
+    /// to confirm it.
+    ///
+    /// A pending checkbox shows its [`Checkbox::pending_symbol`] in place of any other symbol,
+    /// whatever its checked or indeterminate state, so the user can see the change is being
+    /// applied. The default is `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tui_checkbox::Checkbox;
+    ///
+    /// let checkbox = Checkbox::new("Sync", true).pending(true);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn pending(mut self, pending: bool) -> Self {
+        self.pending = pending;
+        self
+    }
+
+    /// Sets the symbol to use when the checkbox is [pending](Checkbox::pending).
+    ///
+    /// The default is `…` (U+2026). Draw a spinner by passing the symbol of the current frame.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tui_checkbox::Checkbox;
+    ///
+    /// const SPINNER: [&str; 4] = ["◐", "◓", "◑", "◒"];
+    /// let frame = 2;
+    /// let checkbox = Checkbox::new("Sync", true)
+    ///     .pending(true)
+    ///     .pending_symbol(SPINNER[frame % SPINNER.len()]);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn pending_symbol<T>(mut self, symbol: T) -> Self
+    where
+        T: Into<Cow<'a, str>>,
+    {
+        self.pending_symbol = symbol.into();
+        self
+    }
+
+    /// Sets the position of the label relative to the checkbox symbol.
+    ///
+    /// The default is [`LabelPosition::Right`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tui_checkbox::{Checkbox, LabelPosition};
+    ///
+    /// let checkbox = Checkbox::new("Option", false).label_position(LabelPosition::Left);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn label_position(mut self, position: LabelPosition) -> Self {
+        self.label_position = position;
+        self
+    }
+
+    /// Sets the number of blank cells between the symbol and a label beside it.
+    ///
+    /// This applies when the label is on the left or right. Use `0` with symbols that already
+    /// include a trailing space. An empty label never gets a gap. The default is `1`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tui_checkbox::Checkbox;
+    ///
+    /// let checkbox = Checkbox::new("Option", true).checked_symbol("✅ ").spacing(0);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn spacing(mut self, spacing: u16) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Sets literal text drawn between the symbol and a label beside it, in the label style.
+    ///
+    /// The separator replaces [`Checkbox::spacing`] and takes its own width. It is not drawn when
+    /// the label is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tui_checkbox::Checkbox;
+    ///
+    /// let checkbox = Checkbox::new("Option", true).separator(" — ");
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn separator(mut self, separator: impl Into<Cow<'a, str>>) -> Self {
+        self.separator = Some(separator.into());
+        self
+    }
+
+    /// Sets the horizontal alignment of the checkbox content within its area.
+    ///
+    /// The default is [`HorizontalAlignment::Left`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tui_checkbox::{Checkbox, HorizontalAlignment};
+    ///
+    /// let checkbox = Checkbox::new("Option", false)
+    ///     .horizontal_alignment(HorizontalAlignment::Center);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn horizontal_alignment(mut self, alignment: HorizontalAlignment) -> Self {
+        self.horizontal_alignment = alignment;
+        self
+    }
+
+    /// Aligns the symbol on its own, independently of the label.
+    ///
+    /// With the label beside the symbol, the symbol is aligned across the whole area and the
+    /// label is aligned by [`Checkbox::horizontal_alignment`] within the space left on its side,
+    /// so a column of checkboxes can keep its symbols flush while the labels are centered. With
+    /// the label above or below, the symbol row is aligned on its own. By default the symbol
+    /// moves along with the label. This has no effect with [`Checkbox::content_width`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tui_checkbox::{Checkbox, HorizontalAlignment, LabelPosition};
+    ///
+    /// // The label on the left and the box flush right
+    /// let checkbox = Checkbox::new("Option", true)
+    ///     .label_position(LabelPosition::Left)
+    ///     .symbol_alignment(HorizontalAlignment::Right);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn symbol_alignment(mut self, alignment: HorizontalAlignment) -> Self {
+        self.symbol_alignment = Some(alignment);
+        self
+    }
+
+    /// Lays the checkbox out as a form row: the label flush left and the symbol flush right.
+    ///
+    /// This is a preset for [`Checkbox::label_position`] on the left, a left
+    /// [`Checkbox::horizontal_alignment`], a right [`Checkbox::symbol_alignment`] and a
+    /// [`Checkbox::full_width_hit`], so the symbol hugs the right edge of the area and the whole
+    /// row is clickable. Combine it with [`Checkbox::max_width`] to keep the row in a column.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tui_checkbox::Checkbox;
+    ///
+    /// // Renders as "Enable dark mode   ☑" in a 20 cell wide area
+    /// let checkbox = Checkbox::new("Enable dark mode", true).form_row();
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn form_row(self) -> Self {
+        self.label_position(LabelPosition::Left)
+            .horizontal_alignment(HorizontalAlignment::Left)
+            .symbol_alignment(HorizontalAlignment::Right)
+            .full_width_hit(true)
+    }
+
+    /// Reserves a column of `width` cells for the symbol, so labels line up across checkboxes
+    /// whose symbols differ in width.
+    ///
+    /// The symbol is aligned within the column by [`Checkbox::symbol_alignment`], on the left by
+    /// default. A symbol wider than the column widens it instead of being cut.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tui_checkbox::Checkbox;
+    ///
+    /// // Both labels start in the fourth column
+    /// let done = Checkbox::new("Done", true).checked_symbol("✅").symbol_width(2);
+    /// let todo = Checkbox::new("Todo", false).symbol_width(2);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn symbol_width(mut self, width: u16) -> Self {
+        self.symbol_width = Some(width);
+        self
+    }
+
+    /// Draws `left` and `right` around the symbol, such as `[ ` and ` ]` for `[ ☑ ]`.
+    ///
+    /// Unlike brackets baked into the symbols, the frame is styled on its own with
+    /// [`Checkbox::frame_style`]. It is part of the symbol column, so its width counts towards
+    /// the width of the checkbox.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tui_checkbox::Checkbox;
+    ///
+    /// let checkbox = Checkbox::new("Option", true).symbol_frame("[ ", " ]");
+    /// assert_eq!(checkbox.to_plain_string(), "[ ☑ ] Option");
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn symbol_frame<L, R>(mut self, left: L, right: R) -> Self
+    where
+        L: Into<Cow<'a, str>>,
+        R: Into<Cow<'a, str>>,
+    {
+        self.symbol_frame = Some((left.into(), right.into()));
+        self
+    }
+
+    /// Sets the style of the [`Checkbox::symbol_frame`], patched over the checkbox style.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::style::{Color, Style};
+    /// use tui_checkbox::Checkbox;
+    ///
+    /// let checkbox = Checkbox::new("Option", true)
+    ///     .symbol_frame("[", "]")
+    ///     .frame_style(Style::default().fg(Color::DarkGray));
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn frame_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.frame_style = style.into();
+        self
+    }
+
+    /// Sets what the symbol is aligned against when the label is above or below it.
+    ///
+    /// With [`SymbolAnchor::LabelEdge`], the symbol is aligned within the columns taken by the
+    /// label instead of the whole area, using [`Checkbox::symbol_alignment`] if set and the
+    /// horizontal alignment otherwise. A centered label can then keep its box under its left
+    /// edge. Has no effect with the label beside the symbol. The default is
+    /// [`SymbolAnchor::AreaEdge`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tui_checkbox::{Checkbox, HorizontalAlignment, LabelPosition, SymbolAnchor};
+    ///
+    /// let checkbox = Checkbox::new("Centered label", true)
+    ///     .label_position(LabelPosition::Top)
+    ///     .horizontal_alignment(HorizontalAlignment::Center)
+    ///     .symbol_alignment(HorizontalAlignment::Left)
+    ///     .symbol_anchor(SymbolAnchor::LabelEdge);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn symbol_anchor(mut self, anchor: SymbolAnchor) -> Self {
+        self.symbol_anchor = anchor;
+        self
+    }
+
+    /// Sets the vertical alignment of the checkbox content within its area.
+    ///
+    /// The default is [`VerticalAlignment::Top`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tui_checkbox::{Checkbox, VerticalAlignment};
+    ///
+    /// let checkbox = Checkbox::new("Option", false)
+    ///     .vertical_alignment(VerticalAlignment::Center);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn vertical_alignment(mut self, alignment: VerticalAlignment) -> Self {
+        self.vertical_alignment = alignment;
+        self
+    }
+
+    /// Sets the row of the symbol against the rows of a label beside it.
+    ///
+    /// By default the symbol sits on the first row of a wrapped label and is centered against a
+    /// label made of several lines. With an alignment, the symbol is placed on the top, middle
+    /// or bottom row of the label block, however the rows came about. This has no effect with
+    /// the label above or below the symbol.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tui_checkbox::{Checkbox, VerticalAlignment};
+    ///
+    /// let checkbox = Checkbox::new("A long label that wraps", false)
+    ///     .wrap_label(true)
+    ///     .symbol_vertical_align(VerticalAlignment::Center);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn symbol_vertical_align(mut self, alignment: VerticalAlignment) -> Self {
+        self.symbol_vertical_alignment = Some(alignment);
+        self
+    }
+
+    /// Sets the minimum width constraint for the checkbox widget.
+    ///
+    /// The default is no minimum width.
+    ///
+    /// The minimum cannot grow the widget past the area it is rendered into: when the area is
+    /// narrower than `width`, the content is laid out, aligned and truncated within the area's
+    /// width instead. Use [`Checkbox::clip_indicator`] to mark this case.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tui_checkbox::Checkbox;
+    ///
+    /// let checkbox = Checkbox::new("Option", false).min_width(20);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn min_width(mut self, width: u16) -> Self {
+        self.min_width = Some(width);
+        self
+    }
+
+    /// Sets the maximum width constraint for the checkbox widget.
+    ///
+    /// The default is no maximum width. The maximum takes precedence over a larger
+    /// [`Checkbox::min_width`].
+    ///
+    /// A maximum narrower than the symbol clips the symbol cell by cell and leaves the label out.
+    /// A wide glyph that would be cut in half is not drawn at all, so no partial glyph ends up in
+    /// the buffer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tui_checkbox::Checkbox;
+    ///
+    /// let checkbox = Checkbox::new("Option", false).max_width(40);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn max_width(mut self, width: u16) -> Self {
+        self.max_width = Some(width);
+        self
+    }
+
+    /// Sets the minimum and maximum width constraints at once.
+    ///
+    /// When `min` is greater than `max`, the maximum wins and both constraints become `max`, the
+    /// same outcome as setting [`Checkbox::min_width`] and [`Checkbox::max_width`] separately with
+    /// an inverted range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tui_checkbox::Checkbox;
+    ///
+    /// let checkbox = Checkbox::new("Option", false).width_range(20, 40);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn width_range(mut self, min: u16, max: u16) -> Self {
+        self.min_width = Some(if min > max { max } else { min });
+        self.max_width = Some(max);
+        self
+    }
+
+    /// Enables or disables label text wrapping.
+    ///
+    /// When enabled, the label will wrap to multiple lines if it exceeds the available width.
+    /// The default is `false` (no wrapping).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tui_checkbox::Checkbox;
+    ///
+    /// let checkbox = Checkbox::new("This is a very long label that should wrap", false)
+    ///     .wrap_label(true)
+    ///     .max_width(30);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn wrap_label(mut self, wrap: bool) -> Self {
+        self.wrap_label = wrap;
+        self
+    }
+
+    /// Sets how the label is broken into lines when [`Checkbox::wrap_label`] is enabled.
+    ///
+    /// [`WrapMode::Char`] suits unbroken tokens such as URLs or hashes. Wide glyphs are never
+    /// split, so [`WrapMode::Char`] and [`WrapMode::WordThenChar`] never produce a line wider
+    /// than the available space unless a single glyph does not fit. The default is
+    /// [`WrapMode::Word`], which clips a word wider than the line.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tui_checkbox::{Checkbox, WrapMode};
+    ///
+    /// let checkbox = Checkbox::new("https://example.com/a/very/long/path", false)
+    ///     .wrap_label(true)
+    ///     .wrap_mode(WrapMode::Char)
+    ///     .max_width(20);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn wrap_mode(mut self, mode: WrapMode) -> Self {
+        self.wrap_mode = mode;
+        self
+    }
+
+    /// Sets the style patched onto every wrapped label line after the first.
+    ///
+    /// This only applies when [`Checkbox::wrap_label`] is enabled and the label takes two or more
+    /// lines, for instance to dim a description that follows a title. The style is patched over
+    /// the label style and the styles of the label spans.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::style::{Modifier, Style};
+    /// use tui_checkbox::Checkbox;
+    ///
+    /// let checkbox = Checkbox::new("Sync: upload files in the background", true)
+    ///     .wrap_label(true)
+    ///     .continuation_style(Style::new().add_modifier(Modifier::DIM));
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn continuation_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.continuation_style = style.into();
+        self
+    }
+
+    /// Sets whether the checkbox has keyboard focus.
+    ///
+    /// The default is `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tui_checkbox::Checkbox;
+    ///
+    /// let checkbox = Checkbox::new("Option", false).focused(true);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn focused(mut self, focused: bool) -> Self {
+        self.focused = focused;
+        self
+    }
+
+    /// Sets whether the checkbox is disabled.
+    ///
+    /// A disabled checkbox still renders its checked state, with the
+    /// [`Checkbox::disabled_style`] patched over the symbol and the label, and
+    /// [`Checkbox::hit_test`] never reports a hit on it. The default is `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tui_checkbox::Checkbox;
+    ///
+    /// let checkbox = Checkbox::new("Sync over cellular", true).disabled(true);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Sets the style patched over the symbol and label when the checkbox is disabled.
+    ///
+    /// The default dims the content with the [`Modifier::DIM`] modifier and a dark gray
+    /// foreground, or the [`ColorScheme::disabled_fg`] of the color scheme when one is set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::style::{Color, Style};
+    /// use tui_checkbox::Checkbox;
+    ///
+    /// let checkbox = Checkbox::new("Option", false)
+    ///     .disabled(true)
+    ///     .disabled_style(Style::default().fg(Color::Gray));
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn disabled_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.disabled_style = Some(style.into());
+        self
+    }
+
+    /// Sets whether the checkbox must be checked, such as to accept terms in a form.
+    ///
+    /// While required and unchecked, a `*` marker in the [`Checkbox::required_style`] is shown
+    /// before the label, and [`Checkbox::is_valid`] returns `false`. The default is `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tui_checkbox::Checkbox;
+    ///
+    /// let checkbox = Checkbox::new("Accept terms", false).required(true);
+    /// assert_eq!(checkbox.to_plain_string(), "☐ * Accept terms");
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn required(mut self, required: bool) -> Self {
+        self.required = required;
+        self
+    }
+
+    /// Sets the style of the [required](Checkbox::required) marker, patched over the label style.
+    ///
+    /// The default is a red foreground.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::style::{Color, Style};
+    /// use tui_checkbox::Checkbox;
+    ///
+    /// let checkbox = Checkbox::new("Accept terms", false)
+    ///     .required(true)
+    ///     .required_style(Style::new().fg(Color::Yellow));
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn required_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.required_style = style.into();
+        self
+    }
+
+    /// Sets the style patched over the symbol and label when the checkbox is focused.
+    ///
+    /// The focus style is applied last, after the checked, label and disabled styles, so a focused
+    /// checkbox is always marked. The default underlines the content; use `Style::new()` to
+    /// leave focus unmarked.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::style::{Modifier, Style};
+    /// use tui_checkbox::Checkbox;
+    ///
+    /// let checkbox = Checkbox::new("Option", false)
+    ///     .focused(true)
+    ///     .focus_style(Style::new().add_modifier(Modifier::REVERSED));
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn focus_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.focus_style = style.into();
+        self
+    }
+
+    /// Sets the styles to cycle through while the checkbox is focused.
+    ///
+    /// The style for the current [`Checkbox::frame`] is patched over both the checkbox symbol and
+    /// the label. This lets an application animate the focus highlight (e.g. a pulsing color) by
+    /// advancing its own frame counter, without the widget owning any timers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::style::{Color, Style};
+    /// use tui_checkbox::Checkbox;
+    ///
+    /// let frames = [Style::default().fg(Color::Cyan), Style::default().fg(Color::Blue)];
+    /// let checkbox = Checkbox::new("Option", false)
+    ///     .focused(true)
+    ///     .focus_style_frames(&frames)
+    ///     .frame(1);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn focus_style_frames(mut self, frames: &[Style]) -> Self {
+        self.focus_style_frames = frames.to_vec();
+        self
+    }
+
+    /// Sets the animation frame used to select a style from [`Checkbox::focus_style_frames`].
+    ///
+    /// The frame index wraps around the number of focus styles, so a monotonically increasing
+    /// counter can be passed directly. The default is `0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tui_checkbox::Checkbox;
+    ///
+    /// let tick = 42;
+    /// let checkbox = Checkbox::new("Option", false).focused(true).frame(tick);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn frame(mut self, frame: usize) -> Self {
+        self.frame = frame;
+        self
+    }
+
+    /// Truncates a label that doesn't fit with an ellipsis (`…`) instead of clipping it.
+    ///
+    /// When the label is wider than the available space, for instance because of
+    /// [`Checkbox::max_width`], it is cut so that the ellipsis takes the last fitting cell. Wide
+    /// glyphs are never split. This is the same as [`Checkbox::truncate_keep_suffix`] with no
+    /// suffix, and `false` turns either off. Has no effect when [`Checkbox::wrap_label`] is
+    /// enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tui_checkbox::Checkbox;
+    ///
+    /// // Renders as "☐ Enable all…"
+    /// let checkbox = Checkbox::new("Enable all features", false).max_width(13).truncate(true);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn truncate(mut self, truncate: bool) -> Self {
+        self.truncate_suffix = if truncate { Some(0) } else { None };
+        self
+    }
+
+    /// Truncates a label that doesn't fit in the middle, keeping its last `chars` characters.
+    ///
+    /// When the label is wider than the available space, the start of the label is kept, the
+    /// middle is replaced by an ellipsis (`…`) and the trailing `chars` characters stay visible.
+    /// This is useful when the end of a label carries meaning, such as a file extension or a
+    /// status. Has no effect when [`Checkbox::wrap_label`] is enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tui_checkbox::Checkbox;
+    ///
+    /// // Renders as e.g. "☐ quarterly_rep…t.pdf" in a narrow area
+    /// let checkbox = Checkbox::new("quarterly_report_final_v2.pdf", false).truncate_keep_suffix(5);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn truncate_keep_suffix(mut self, chars: usize) -> Self {
+        self.truncate_suffix = Some(chars);
+        self
+    }
+
+    /// Fades out the last cells of a label line that is clipped at the edge of its area.
+    ///
+    /// The last three visible cells of the clipped line get [`Modifier::DIM`], hinting that the
+    /// label goes on, as an alternative to the ellipsis of [`Checkbox::truncate`]. Lines that fit
+    /// are left as they are. Has no effect when [`Checkbox::wrap_label`] is enabled or when the
+    /// label is truncated, since the line then fits. The default is `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tui_checkbox::Checkbox;
+    ///
+    /// let checkbox = Checkbox::new("Enable all features", false)
+    ///     .max_width(13)
+    ///     .fade_overflow(true);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn fade_overflow(mut self, fade_overflow: bool) -> Self {
+        self.fade_overflow = fade_overflow;
+        self
+    }
+
+    /// Draws the checkbox symbol and label on a shared "pill" highlight.
+    ///
+    /// The pill paints [`Checkbox::pill_style`] across the symbol, the separator and the label,
+    /// and adds a rounded half-block cap on each side. The caps take one cell each and are
+    /// included in the widget width. Only applies to [`LabelPosition::Left`] and
+    /// [`LabelPosition::Right`]. The default is `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::style::{Color, Style};
+    /// use tui_checkbox::Checkbox;
+    ///
+    /// let checkbox = Checkbox::new("Option", true)
+    ///     .pill(true)
+    ///     .pill_style(Style::default().bg(Color::Blue));
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn pill(mut self, pill: bool) -> Self {
+        self.pill = pill;
+        self
+    }
+
+    /// Sets the style of the pill highlight enabled with [`Checkbox::pill`].
+    ///
+    /// The background color of this style is the pill color and is also used to draw the caps.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::style::{Color, Style};
+    /// use tui_checkbox::Checkbox;
+    ///
+    /// let checkbox = Checkbox::new("Option", true)
+    ///     .pill(true)
+    ///     .pill_style(Style::default().bg(Color::Blue).fg(Color::White));
+    /// ```
+    ///
+    /// [`Color`]: ratatui::style::Color
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn pill_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.pill_style = style.into();
+        self
+    }
+
+    /// Sets nested options rendered as indented checkboxes below the main checkbox.
+    ///
+    /// Each `(label, checked)` pair is drawn on its own row beneath the main checkbox, indented
+    /// so its symbol lines up with the start of the main label. Sub-options share the symbols,
+    /// [`Checkbox::checkbox_style`] and [`Checkbox::label_style`] of the main checkbox. This is a
+    /// lightweight way to show a few related options without a full group of widgets.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tui_checkbox::Checkbox;
+    ///
+    /// let checkbox = Checkbox::new("Notifications", true).sub_options(vec![
+    ///     ("Email".to_string(), true),
+    ///     ("Push".to_string(), false),
+    /// ]);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn sub_options(mut self, options: Vec<(String, bool)>) -> Self {
+        self.sub_options = options;
+        self
+    }
+
+    /// Sets the region of the widget that the base [`Checkbox::style`] is painted over.
+    ///
+    /// The default is [`StyleScope::All`], which also tints the block borders. Use
+    /// [`StyleScope::Inner`] to keep the block untouched, or [`StyleScope::Content`] to only style
+    /// the checkbox symbol and label cells.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::style::{Color, Style};
+    /// use ratatui::widgets::Block;
+    /// use tui_checkbox::{Checkbox, StyleScope};
+    ///
+    /// let checkbox = Checkbox::new("Option", false)
+    ///     .block(Block::bordered())
+    ///     .style(Style::default().bg(Color::Blue))
+    ///     .style_scope(StyleScope::Inner);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn style_scope(mut self, scope: StyleScope) -> Self {
+        self.style_scope = scope;
+        self
+    }
+
+    /// Renders the checkbox as partially filled, for progress-style toggles.
+    ///
+    /// `ratio` ranges from `0.0` to `1.0` and is clamped to that range. Ratios in between pick a
+    /// proportional fill glyph from [`symbols::PARTIAL_FILL`] (`▁` to `▇`), while `0.0` and `1.0`
+    /// use the unchecked and checked symbols. The ratio is rounded to the nearest eighth, the
+    /// resolution of the fill glyphs. This overrides the symbol chosen by [`Checkbox::checked`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tui_checkbox::Checkbox;
+    ///
+    /// // Renders as "▄ Downloading"
+    /// let checkbox = Checkbox::new("Downloading", false).partial(0.5);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    #[expect(clippy::cast_sign_loss)] // The ratio is clamped to be non-negative
+    pub fn partial(mut self, ratio: f32) -> Self {
+        self.partial = Some((ratio.clamp(0.0, 1.0) * 8.0).round() as u8);
+        self
+    }
+
+    /// Animates checking over `frames` frames, filling the symbol left to right with shades.
+    ///
+    /// While a checked checkbox is at an [`Checkbox::animation_frame`] before `frames`, each cell
+    /// of the symbol steps through the [`symbols::SHADES`] from left to right, and from frame
+    /// `frames` on the checked symbol is shown. The widget owns no timer: the application
+    /// advances the frame, for instance once per tick after the checkbox was checked.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tui_checkbox::Checkbox;
+    ///
+    /// // Renders as "▒ Option", halfway through the animation
+    /// let checkbox = Checkbox::new("Option", true)
+    ///     .check_animation(4)
+    ///     .animation_frame(2);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn check_animation(mut self, frames: u16) -> Self {
+        self.check_animation = Some(frames);
+        self
+    }
+
+    /// Sets the current frame of the [`Checkbox::check_animation`].
+    ///
+    /// Frame `0` shows an empty symbol and the animation is complete once the frame reaches the
+    /// number of animation frames. Without a frame, the checkbox renders its final state.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn animation_frame(mut self, current: u16) -> Self {
+        self.animation_frame = Some(current);
+        self
+    }
+
+    /// Sets the intermediate symbols shown while [`Checkbox::render_frame`] transitions between
+    /// states.
+    ///
+    /// The symbols are listed in order from unchecked to checked and played in reverse when the
+    /// checkbox is unchecked. Without transition symbols, the transition switches from the old
+    /// symbol to the new one halfway through.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tui_checkbox::Checkbox;
+    ///
+    /// let checkbox = Checkbox::new("Option", true).transition_symbols(["◔", "◑", "◕"]);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn transition_symbols<S>(mut self, symbols: impl IntoIterator<Item = S>) -> Self
+    where
+        S: Into<Cow<'a, str>>,
+    {
+        self.transition_symbols = symbols.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Sets the role-based [`ColorScheme`] of the checkbox.
+    ///
+    /// The scheme colors the symbol according to the checked state, the label, and the background
+    /// when [focused](Checkbox::focused). [`Checkbox::checkbox_style`] and
+    /// [`Checkbox::label_style`] still take precedence over the scheme colors.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tui_checkbox::{Checkbox, ColorScheme};
+    ///
+    /// let checkbox = Checkbox::new("Option", true).color_scheme(ColorScheme::dark());
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn color_scheme(mut self, scheme: ColorScheme) -> Self {
+        self.color_scheme = Some(scheme);
+        self
+    }
+
+    /// Colors the symbol by its state: green when checked and dark gray otherwise.
+    ///
+    /// This saves setting a [`Checkbox::checkbox_style`] per state for the common success and
+    /// muted colors. The automatic color replaces the symbol color of a
+    /// [`Checkbox::color_scheme`], and an explicit [`Checkbox::checkbox_style`] foreground still
+    /// takes precedence over it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tui_checkbox::Checkbox;
+    ///
+    /// let checkbox = Checkbox::new("Option", true).auto_color(true);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn auto_color(mut self, auto_color: bool) -> Self {
+        self.auto_color = auto_color;
+        self
+    }
+
+    /// Renders the checkbox without colors, for terminals without color support or when
+    /// `NO_COLOR` is set.
+    ///
+    /// The foreground and background colors are removed from every style as it is composed at
+    /// render time, including those of the block, while modifiers such as bold and underline are
+    /// kept. Cells then show the colors already in the buffer, such as the background of a parent
+    /// panel. The state stays distinguishable by the symbol alone. The configured styles are left
+    /// as they are, so turning this off restores the colors.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tui_checkbox::Checkbox;
+    ///
+    /// let checkbox =
+    ///     Checkbox::new("Option", true).monochrome(std::env::var_os("NO_COLOR").is_some());
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn monochrome(mut self, monochrome: bool) -> Self {
+        self.monochrome = monochrome;
+        self
+    }
+
+    /// Enables or disables bidirectional reordering of the label.
+    ///
+    /// When enabled, each rendered label line is reordered from logical to visual order with the
+    /// Unicode bidi algorithm, so labels mixing left-to-right and right-to-left scripts (e.g.
+    /// English and Arabic) display correctly. Span styles follow their characters. The default is
+    /// `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tui_checkbox::Checkbox;
+    ///
+    /// let checkbox = Checkbox::new("Language: العربية", true).bidi(true);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn bidi(mut self, bidi: bool) -> Self {
+        self.bidi = bidi;
+        self
+    }
+
+    /// Lays the checkbox out for right-to-left text.
+    ///
+    /// The label position and horizontal alignment are mirrored, so [`LabelPosition::Right`]
+    /// places the label visually to the left of the symbol and [`HorizontalAlignment::Left`]
+    /// aligns content to the right edge. Wrapped label lines are aligned to the right. Combine
+    /// with [`Checkbox::bidi`] to also reorder the characters of mixed-direction labels. The
+    /// default is `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tui_checkbox::Checkbox;
+    ///
+    /// let checkbox = Checkbox::new("تفعيل الإشعارات", true).rtl(true).bidi(true);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn rtl(mut self, rtl: bool) -> Self {
+        self.rtl = rtl;
+        self
+    }
+
+    /// Extends the clickable area across the full width of the content rows.
+    ///
+    /// When enabled, [`Checkbox::hit_test`] accepts any cell on the rows of the symbol and label
+    /// within the constrained width, not just the glyphs, which makes short labels easier to
+    /// click. The base [`Checkbox::style`] is painted across the same cells so the selectable
+    /// region is visible, even with [`StyleScope::Content`]. Use [`Checkbox::max_width`] to limit
+    /// the row to a column. The default is `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::layout::Rect;
+    /// use tui_checkbox::Checkbox;
+    ///
+    /// let checkbox = Checkbox::new("Option", false).max_width(20).full_width_hit(true);
+    /// assert!(checkbox.hit_test(Rect::new(0, 0, 30, 1), 15, 0));
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn full_width_hit(mut self, full_width_hit: bool) -> Self {
+        self.full_width_hit = full_width_hit;
+        self
+    }
+
+    /// Marks keyboard focus with corner glyphs (`⌜ ⌝ ⌞ ⌟`) instead of a full block.
+    ///
+    /// When enabled, a 1-cell margin is reserved around the content (inside the block, if any)
+    /// and the corner glyphs are drawn in it while the checkbox is [focused](Checkbox::focused).
+    /// The margin is kept when unfocused so the content doesn't shift as focus moves. The corners
+    /// use the checkbox symbol style. The default is `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tui_checkbox::Checkbox;
+    ///
+    /// let checkbox = Checkbox::new("Option", false)
+    ///     .focus_corners(true)
+    ///     .focused(true);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn focus_corners(mut self, focus_corners: bool) -> Self {
+        self.focus_corners = focus_corners;
+        self
+    }
+
+    /// Draws the label with two cells per glyph, as a crude large-text effect.
+    ///
+    /// This is an experimental accessibility option for low-vision users: each label glyph is
+    /// followed by a space, doubling the label width. Wrapping and truncation account for the
+    /// doubled width. The default is `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tui_checkbox::Checkbox;
+    ///
+    /// // Renders as "☐ O p t i o n "
+    /// let checkbox = Checkbox::new("Option", false).double_width_label(true);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn double_width_label(mut self, double_width: bool) -> Self {
+        self.double_width_label = double_width;
+        self
+    }
+
+    /// Sets a fixed width for the content box, with the label left and the symbol right.
+    ///
+    /// This lays out a settings-style row in [`LabelPosition::Left`] and [`LabelPosition::Right`]:
+    /// the label starts at the left edge of a box of `width` columns and the symbol sits at its
+    /// right edge. The box itself is positioned within the area by
+    /// [`Checkbox::horizontal_alignment`] and is shrunk to fit the area if needed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tui_checkbox::{Checkbox, HorizontalAlignment};
+    ///
+    /// // Renders as "Dark mode          ☑" centered in the area
+    /// let checkbox = Checkbox::new("Dark mode", true)
+    ///     .content_width(20)
+    ///     .horizontal_alignment(HorizontalAlignment::Center);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn content_width(mut self, width: u16) -> Self {
+        self.content_width = Some(width);
+        self
+    }
+
+    /// Sets whether to draw an indicator at the right edge when the minimum width does not fit.
+    ///
+    /// When [`Checkbox::min_width`] is larger than the area, the content is constrained to the
+    /// area's width and [`symbols::CLIP_INDICATOR`] is drawn in its last column, on the row of the
+    /// symbol, to signal that it was cut short. The default is `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tui_checkbox::Checkbox;
+    ///
+    /// let checkbox = Checkbox::new("Option", false).min_width(30).clip_indicator(true);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn clip_indicator(mut self, clip_indicator: bool) -> Self {
+        self.clip_indicator = clip_indicator;
+        self
+    }
+
+    /// Sets a hint pinned to the right edge of the area, such as a keybinding chord.
+    ///
+    /// The hint is drawn flush-right on the row of the symbol, and its width plus a one column gap
+    /// is reserved before the symbol and label are laid out, so a long label is wrapped or
+    /// truncated instead of running into it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::style::Stylize;
+    /// use tui_checkbox::Checkbox;
+    ///
+    /// // Renders as "☐ Show hidden files    Ctrl+H"
+    /// let checkbox = Checkbox::new("Show hidden files", false).right_hint("Ctrl+H".dark_gray());
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn right_hint<T>(mut self, hint: T) -> Self
+    where
+        T: Into<Span<'a>>,
+    {
+        self.right_hint = Some(hint.into());
+        self
+    }
+
+    /// Sets a decoration drawn right before the symbol and label, such as a bullet.
+    ///
+    /// The prefix sits on the row of the symbol at the visual start of the content, whatever the
+    /// [`Checkbox::label_position`], and its width counts in [`Checkbox::measure`]. Include any
+    /// spacing in the span itself. The prefix and [`Checkbox::suffix`] are left out when the area
+    /// is too narrow to fit them next to the symbol.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tui_checkbox::Checkbox;
+    ///
+    /// // Renders as "• ☐ Option"
+    /// let checkbox = Checkbox::new("Option", false).prefix("• ");
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn prefix<T>(mut self, prefix: T) -> Self
+    where
+        T: Into<Span<'a>>,
+    {
+        self.prefix = Some(prefix.into());
+        self
+    }
+
+    /// Sets the nesting depth of the checkbox, for settings rendered as a tree.
+    ///
+    /// Each level reserves three columns at the left edge of the area, before the
+    /// [`Checkbox::prefix`] and the symbol, and counts in [`Checkbox::measure`]. The indentation
+    /// is blank unless [`Checkbox::tree_guides`] is enabled. The default is `0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tui_checkbox::Checkbox;
+    ///
+    /// // Renders as "   ☐ Child"
+    /// let checkbox = Checkbox::new("Child", false).indent(1);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn indent(mut self, level: u16) -> Self {
+        self.indent = level;
+        self
+    }
+
+    /// Sets whether to draw box-drawing guides in the indentation set by [`Checkbox::indent`].
+    ///
+    /// Every ancestor level is drawn as [`symbols::TREE_PIPE`] and the level of the checkbox as
+    /// [`symbols::TREE_BRANCH`], on the row of the symbol. The default is `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tui_checkbox::Checkbox;
+    ///
+    /// // Renders as "│  ├─ ☐ Grandchild"
+    /// let checkbox = Checkbox::new("Grandchild", false)
+    ///     .indent(2)
+    ///     .tree_guides(true);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn tree_guides(mut self, tree_guides: bool) -> Self {
+        self.tree_guides = tree_guides;
+        self
+    }
+
+    /// Sets the style of the tree guides drawn by [`Checkbox::tree_guides`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::style::{Color, Style};
+    /// use tui_checkbox::Checkbox;
+    ///
+    /// let checkbox = Checkbox::new("Child", false)
+    ///     .indent(1)
+    ///     .tree_guides(true)
+    ///     .guide_style(Style::default().fg(Color::DarkGray));
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn guide_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.guide_style = style.into();
+        self
+    }
+
+    /// Sets a decoration drawn right after the symbol and label, such as a count badge.
+    ///
+    /// The suffix sits on the row of the symbol at the visual end of the content and follows the
+    /// same rules as [`Checkbox::prefix`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::style::Stylize;
+    /// use tui_checkbox::Checkbox;
+    ///
+    /// // Renders as "☐ Inbox (12)"
+    /// let checkbox = Checkbox::new("Inbox", false).suffix(" (12)".dark_gray());
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn suffix<T>(mut self, suffix: T) -> Self
+    where
+        T: Into<Span<'a>>,
+    {
+        self.suffix = Some(suffix.into());
+        self
+    }
+
+    /// Applies `f` to the checkbox when `condition` is true, and returns it unchanged otherwise.
+    ///
+    /// This keeps conditional configuration, such as a selection highlight, inside a builder
+    /// chain.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::style::{Color, Style};
+    /// use tui_checkbox::Checkbox;
+    ///
+    /// let selected = true;
+    /// let checkbox = Checkbox::new("Option", false)
+    ///     .when(selected, |checkbox| checkbox.style(Style::default().bg(Color::DarkGray)));
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn when<F>(self, condition: bool, f: F) -> Self
+    where
+        F: FnOnce(Self) -> Self,
+    {
+        if condition {
+            f(self)
+        } else {
+            self
+        }
+    }
+
+    /// Returns the `(width, height)` the checkbox renders into when given `max_width` columns.
+    ///
+    /// The size accounts for the symbol width, the gap between symbol and label, the label
+    /// position, wrapping, width constraints and any block, so it can be used to size a
+    /// [`Constraint::Length`] before splitting a layout. The width never exceeds `max_width`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tui_checkbox::{Checkbox, LabelPosition};
+    ///
+    /// assert_eq!(Checkbox::new("Option", false).measure(40), (8, 1));
+    /// assert_eq!(
+    ///     Checkbox::new("Option", false)
+    ///         .label_position(LabelPosition::Top)
+    ///         .measure(40),
+    ///     (6, 2)
+    /// );
+    /// ```
+    ///
+    /// [`Constraint::Length`]: ratatui::layout::Constraint::Length
+    #[must_use]
+    pub fn measure(&self, max_width: u16) -> (u16, u16) {
+        let (chrome_width, chrome_height) = self.chrome_size();
+        let mut available = max_width.saturating_sub(chrome_width);
+        if let Some(max) = self.max_width {
+            available = available.min(max);
+        }
+
+        let guide_width = self.guide_width(available);
+        available -= guide_width;
+        let hint_width = self.hint_overhead(available);
+        available -= hint_width;
+
+        let symbol_width = self.symbol_column_width();
+        let (prefix_width, suffix_width) = self.decoration_widths(available, symbol_width);
+        let decoration_width = prefix_width + suffix_width;
+        available -= decoration_width;
+
+        let label = self.styled_label(Style::default());
+        let (mut width, mut height) = match self.visual_label_position() {
+            LabelPosition::Right | LabelPosition::Left => {
+                let pill_width = 2 * u16::from(self.pill);
+                let fixed_width = self
+                    .content_width
+                    .map(|width| width.min(available.saturating_sub(pill_width)));
+                let overhead = self.horizontal_overhead(symbol_width);
+                let lines = self.label_lines(
+                    label,
+                    fixed_width
+                        .map_or(available, |width| width + pill_width)
+                        .saturating_sub(overhead),
+                    None,
+                );
+                // An empty label leaves no gap next to the symbol
+                let label_width = max_line_width(&lines);
+                let gap_width = if label_width == 0 {
+                    self.gap_width()
+                } else {
+                    0
+                };
+                let width = fixed_width.map_or(overhead - gap_width + label_width, |width| {
+                    width + pill_width
+                });
+                (width, lines.len() as u16)
+            }
+            LabelPosition::Top | LabelPosition::Bottom => {
+                let lines = self.label_lines(label, available, None);
+                let label_width = max_line_width(&lines);
+                let label_height = if label_width == 0 {
+                    0
+                } else {
+                    lines.len() as u16
+                };
+                (symbol_width.max(label_width), 1 + label_height)
+            }
+        };
+        height = height.saturating_sub(self.scroll);
+
+        // Sub-options are indented to the start of the label, one row each
+        for (label, _) in &self.sub_options {
+            width = width.max(2 * (symbol_width + 1) + Span::raw(label).width() as u16);
+        }
+        height += self.sub_options.len() as u16;
+
+        if let Some(min) = self.min_width {
+            width = width.max(min);
+        }
+        (
+            (width.min(available) + decoration_width + guide_width + hint_width + chrome_width)
+                .min(max_width),
+            height + chrome_height,
+        )
+    }
+
+    /// Returns the `(minimum, desired)` widths of the checkbox, for flexible layouts.
+    ///
+    /// The desired width fits the whole label without wrapping, as [`Checkbox::measure`] reports
+    /// it with unlimited space. When [`Checkbox::wrap_label`] is enabled, the minimum width
+    /// wraps the label at its longest word, so no word has to be cut; otherwise the label cannot
+    /// get narrower without being truncated and both widths are the same.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tui_checkbox::Checkbox;
+    ///
+    /// let checkbox = Checkbox::new("Enable dark mode", false).wrap_label(true);
+    /// assert_eq!(checkbox.width_bounds(), (8, 18));
+    /// ```
+    #[must_use]
+    pub fn width_bounds(&self) -> (u16, u16) {
+        let desired = self.measure(u16::MAX).0;
+        if !self.wrap_label {
+            return (desired, desired);
+        }
+
+        // Narrowing the space by the difference between the widest line and the longest word
+        // wraps the label at that word
+        let scale = if self.double_width_label { 2 } else { 1 };
+        let longest_word = self
+            .label
+            .lines
+            .iter()
+            .filter_map(|line| {
+                let text = line.to_string();
+                text.split(' ').map(UnicodeWidthStr::width).max()
+            })
+            .max()
+            .unwrap_or(0);
+        let excess = (self.label.width().saturating_sub(longest_word) * scale) as u16;
+        let minimum = self.measure(desired.saturating_sub(excess)).0;
+        (minimum.min(desired), desired)
+    }
+
+    /// Returns whether the cell at `column` and `row` falls on the rendered symbol or label when
+    /// the checkbox is rendered into `area`.
+    ///
+    /// The geometry matches [`Widget::render`]: the block insets, the focus corner margin, the
+    /// width constraints, the label position and the alignment are all taken into account, so a
+    /// mouse click can be mapped back to the checkbox it toggles. Padding around the content,
+    /// the gap between symbol and label and sub-option rows are not hits, unless
+    /// [`Checkbox::full_width_hit`] extends the hit area across the content rows. Nothing is a
+    /// hit on a [disabled](Checkbox::disabled) checkbox.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::layout::Rect;
+    /// use tui_checkbox::Checkbox;
+    ///
+    /// let checkbox = Checkbox::new("Option", false);
+    /// let area = Rect::new(0, 0, 20, 1);
+    ///
+    /// assert!(checkbox.hit_test(area, 0, 0)); // symbol
+    /// assert!(checkbox.hit_test(area, 4, 0)); // label
+    /// assert!(!checkbox.hit_test(area, 15, 0)); // padding
+    /// ```
+    #[must_use]
+    pub fn hit_test(&self, area: Rect, column: u16, row: u16) -> bool {
+        if self.disabled {
+            return false;
+        }
+        let Some((render_area, placement)) = self.layout(area) else {
+            return false;
+        };
+
+        let position = Position::new(column, row);
+        if self.full_width_hit {
+            return self
+                .content_bounds(render_area, &placement)
+                .is_some_and(|bounds| bounds.contains(position));
+        }
+        placement.symbol.contains(position)
+            || placement.hint.contains(position)
+            || placement.pill.is_some_and(|pill| pill.contains(position))
+            || placement
+                .label
+                .iter()
+                .any(|(label_area, _)| label_area.contains(position))
+    }
+
+    /// Renders the checkbox and returns the area it actually painted.
+    ///
+    /// Unlike [`Checkbox::measure`], the returned rect reflects the placement within `area` after
+    /// alignment and label positioning: it is the smallest rect covering the symbol, label, hint,
+    /// pill and sub-options as drawn. A block or focus corners paint the whole `area`, so it is
+    /// returned as is. Nothing painted yields an empty rect at the origin of `area`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::{buffer::Buffer, layout::Rect};
+    /// use tui_checkbox::{Checkbox, HorizontalAlignment};
+    ///
+    /// let mut buf = Buffer::empty(Rect::new(0, 0, 20, 3));
+    /// let painted = Checkbox::new("Option", true)
+    ///     .horizontal_alignment(HorizontalAlignment::Right)
+    ///     .render_and_measure(buf.area, &mut buf);
+    ///
+    /// // A tooltip can be drawn right below the checkbox
+    /// assert_eq!(painted, Rect::new(12, 0, 8, 1));
+    /// ```
+    pub fn render_and_measure(self, area: Rect, buf: &mut Buffer) -> Rect {
+        Widget::render(&self, area, buf);
+        if self.block.is_some() || (self.focus_corners && self.focused) {
+            return area;
+        }
+        let Some((render_area, placement)) = self.layout(area) else {
+            return Rect::new(area.x, area.y, 0, 0);
+        };
+
+        let mut painted: Vec<_> = self
+            .content_bounds(render_area, &placement)
+            .into_iter()
+            .collect();
+
+        // Sub-options are rendered one per row below the checkbox, indented past the symbol
+        let indent = self.symbol_column_width() + 1;
+        let sub_rows = render_area.rows().skip(usize::from(placement.used_rows));
+        for (row, (label, checked)) in sub_rows.zip(&self.sub_options) {
+            let available = row.width.saturating_sub(indent);
+            let (width, _) = Checkbox::new(label.as_str(), *checked)
+                .checked_symbol(self.checked_symbol.as_ref())
+                .unchecked_symbol(self.unchecked_symbol.as_ref())
+                .measure(available);
+            painted.push(Rect::new(row.x + indent.min(row.width), row.y, width, 1));
+        }
+
+        painted
+            .into_iter()
+            .filter(|rect| !rect.is_empty())
+            .reduce(Rect::union)
+            .unwrap_or(Rect::new(area.x, area.y, 0, 0))
+    }
+
+    /// Returns the checkbox as plain text on a single line, for logging and snapshot tests.
+    ///
+    /// The text is the current symbol, the gap or [`Checkbox::separator`] and the label, in the
+    /// order given by [`Checkbox::label_position`]: the label comes first when it is on the left
+    /// and after the symbol otherwise. Lines of a multi-line label are joined with spaces, and
+    /// alignment, wrapping and width constraints are ignored. Styles are dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tui_checkbox::Checkbox;
+    ///
+    /// assert_eq!(Checkbox::new("Option", true).to_plain_string(), "☑ Option");
+    /// ```
+    #[must_use]
+    pub fn to_plain_string(&self) -> String {
+        self.to_spans()
+            .iter()
+            .map(|span| span.content.as_ref())
+            .collect()
+    }
+
+    /// Returns the checkbox as styled spans, to embed it in a [`Line`] of other text.
+    ///
+    /// The spans are the symbol, the gap or [`Checkbox::separator`] and the label, in the same
+    /// order and with the same text as [`Checkbox::to_plain_string`]. Each span carries the
+    /// style the checkbox renders it with, including the focus, disabled and color scheme
+    /// styles, while the block and the layout options are left out.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::text::{Line, Span};
+    /// use tui_checkbox::Checkbox;
+    ///
+    /// let mut line = Line::from("Settings: ");
+    /// line.spans.extend(Checkbox::new("Dark mode", true).to_spans());
+    /// assert_eq!(line.to_string(), "Settings: ☑ Dark mode");
+    /// ```
+    #[must_use]
+    pub fn to_spans(&self) -> Vec<Span<'static>> {
+        let (checkbox_style, label_style) = self.content_styles();
+        let mut lines = self.styled_label(label_style);
+        if let Some((mut fill, value)) = self.split_value(&mut lines) {
+            fill.content = self.fill_char.to_string().into();
+            lines[0].spans.extend([fill, value]);
+        }
+        let mut label = Vec::new();
+        for (i, line) in lines.into_iter().enumerate() {
+            if i > 0 {
+                label.push(Span::styled(" ", label_style));
+            }
+            label.extend(line.spans.into_iter().map(owned_span));
+        }
+        let mut symbol: Vec<_> = self
+            .symbol_line(checkbox_style)
+            .spans
+            .into_iter()
+            .map(owned_span)
+            .collect();
+        if label.iter().all(|span| span.content.is_empty()) {
+            return symbol;
+        }
+        if self.has_inline_symbol() {
+            return label;
+        }
+
+        // The gap takes the label background, as it does when rendered
+        let gap = self.separator.as_ref().map_or_else(
+            || {
+                let style = Style {
+                    bg: label_style.bg,
+                    ..Style::new()
+                };
+                Span::styled(" ".repeat(usize::from(self.spacing)), style)
+            },
+            |separator| Span::styled(separator.to_string(), label_style),
+        );
+        if self.visual_label_position() == LabelPosition::Left {
+            label.push(gap);
+            label.append(&mut symbol);
+            label
+        } else {
+            symbol.push(gap);
+            symbol.append(&mut label);
+            symbol
+        }
+    }
+
+    /// Returns a description of the checkbox for screen readers and text-to-speech.
+    ///
+    /// The description names the role, the state (`checked`, `unchecked` or `indeterminate`)
+    /// and the [`Checkbox::accessible_label`], falling back to the label with its lines joined by
+    /// spaces, followed by `disabled` when the checkbox is disabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tui_checkbox::Checkbox;
+    ///
+    /// let checkbox = Checkbox::new("Enable feature", true);
+    /// assert_eq!(
+    ///     checkbox.accessible_description(),
+    ///     "checkbox, checked, Enable feature"
+    /// );
+    /// ```
+    #[must_use]
+    pub fn accessible_description(&self) -> String {
+        let state = if self.indeterminate {
+            "indeterminate"
+        } else if self.checked {
+            "checked"
+        } else {
+            "unchecked"
+        };
+        let label = self.accessible_label.clone().unwrap_or_else(|| {
+            self.label
+                .lines
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join(" ")
+        });
+        let mut description = format!("checkbox, {state}, {label}");
+        if self.disabled {
+            description.push_str(", disabled");
+        }
+        description
+    }
+
+    /// Returns the rows of the checkbox as rendered within `width` columns, for snapshot tests.
+    ///
+    /// Unlike [`Checkbox::to_plain_string`], this reflects the full layout: the checkbox is
+    /// rendered into an area `width` columns wide and as tall as [`Checkbox::measure`] reports,
+    /// including alignment, wrapping and the block. Each row keeps only the symbols of its cells,
+    /// without trailing whitespace.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tui_checkbox::Checkbox;
+    ///
+    /// let checkbox = Checkbox::new("Enable all features", true).wrap_label(true);
+    /// assert_eq!(checkbox.to_lines(12), ["☑ Enable all", "  features"]);
+    /// ```
+    #[must_use]
+    pub fn to_lines(&self, width: u16) -> Vec<String> {
+        let (_, height) = self.measure(width);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, width, height));
+        Widget::render(self, buffer.area, &mut buffer);
+        buffer_rows(&buffer)
+    }
+
+    /// Renders a frame of the transition into the current checked state.
+    ///
+    /// `progress` ranges from `0.0`, which shows the symbol of the opposite state, to `1.0`,
+    /// which shows the current symbol, and is clamped to that range. Progress in between steps
+    /// through the [`Checkbox::transition_symbols`]. The widget keeps no state: the application
+    /// tracks when the checkbox was toggled and drives the progress from its own clock.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::{buffer::Buffer, layout::Rect};
+    /// use tui_checkbox::Checkbox;
+    ///
+    /// let checkbox = Checkbox::new("Option", true).transition_symbols(["◔", "◑", "◕"]);
+    /// let mut buf = Buffer::empty(Rect::new(0, 0, 10, 1));
+    ///
+    /// // Renders as "◑ Option"
+    /// checkbox.render_frame(buf.area, &mut buf, 0.5);
+    /// ```
+    #[expect(clippy::cast_sign_loss, clippy::cast_precision_loss)] // Progress is clamped
+    pub fn render_frame(&self, area: Rect, buf: &mut Buffer, progress: f32) {
+        let steps = self.transition_symbols.len() + 1;
+        let step = (progress.clamp(0.0, 1.0) * steps as f32).round() as usize;
+        let mut frame = self.clone();
+        if step == 0 {
+            frame.checked = !self.checked;
+        } else if step < steps {
+            let index = if self.checked { step } else { steps - step } - 1;
+            // The transition symbol replaces whatever symbol the state would show otherwise
+            frame.checked = true;
+            frame.indeterminate = false;
+            frame.pending = false;
+            frame.partial = None;
+            frame.check_animation = None;
+            frame.checked_symbol_line = None;
+            frame
+                .checked_symbol
+                .clone_from(&self.transition_symbols[index]);
+        }
+        Widget::render(&frame, area, buf);
+    }
+
+    /// Converts the checkbox into one that owns all of its text, detaching it from the
+    /// lifetime of borrowed labels and symbols.
+    ///
+    /// The result can be stored past the scope of the data it was built from, such as in
+    /// long-lived application state, and renders like the original without its block. A
+    /// [`Block`] cannot be detached from its borrows, so the [`Checkbox::block`] is always
+    /// dropped, and has to be set again on the owned checkbox, for example from a block kept
+    /// alongside it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::widgets::Block;
+    /// use tui_checkbox::Checkbox;
+    ///
+    /// let owned: Checkbox<'static> = {
+    ///     let label = String::from("Enable feature");
+    ///     Checkbox::new(label.as_str(), true)
+    ///         .block(Block::bordered())
+    ///         .into_owned()
+    /// };
+    /// assert_eq!(owned.to_plain_string(), "☑ Enable feature");
+    /// let owned = owned.block(Block::bordered());
+    /// ```
+    #[must_use]
+    pub fn into_owned(self) -> Checkbox<'static> {
+        Checkbox {
+            label: Text {
+                lines: self.label.lines.into_iter().map(owned_line).collect(),
+                ..self.label
+            },
+            value: self.value.map(owned_span),
+            description: self.description.map(owned_line),
+            description_style: self.description_style,
+            description_on_focus: self.description_on_focus,
+            fill_char: self.fill_char,
+            tab_width: self.tab_width,
+            label_case: self.label_case,
+            checked: self.checked,
+            block: None,
+            block_checked_style: self.block_checked_style,
+            style: self.style,
+            checkbox_style: self.checkbox_style,
+            label_style: self.label_style,
+            strikethrough_when_checked: self.strikethrough_when_checked,
+            checked_symbol: owned_cow(self.checked_symbol),
+            checked_symbol_line: self.checked_symbol_line.map(owned_line),
+            unchecked_symbol: owned_cow(self.unchecked_symbol),
+            indeterminate: self.indeterminate,
+            indeterminate_symbol: owned_cow(self.indeterminate_symbol),
+            pending: self.pending,
+            pending_symbol: owned_cow(self.pending_symbol),
+            label_position: self.label_position,
+            spacing: self.spacing,
+            separator: self.separator.map(owned_cow),
+            horizontal_alignment: self.horizontal_alignment,
+            symbol_alignment: self.symbol_alignment,
+            symbol_vertical_alignment: self.symbol_vertical_alignment,
+            symbol_width: self.symbol_width,
+            symbol_frame: self
+                .symbol_frame
+                .map(|(left, right)| (owned_cow(left), owned_cow(right))),
+            frame_style: self.frame_style,
+            symbol_anchor: self.symbol_anchor,
+            vertical_alignment: self.vertical_alignment,
+            min_width: self.min_width,
+            max_width: self.max_width,
+            wrap_label: self.wrap_label,
+            wrap_mode: self.wrap_mode,
+            continuation_style: self.continuation_style,
+            focused: self.focused,
+            focus_style: self.focus_style,
+            focus_style_frames: self.focus_style_frames,
+            frame: self.frame,
+            truncate_suffix: self.truncate_suffix,
+            fade_overflow: self.fade_overflow,
+            pill: self.pill,
+            pill_style: self.pill_style,
+            sub_options: self.sub_options,
+            style_scope: self.style_scope,
+            partial: self.partial,
+            check_animation: self.check_animation,
+            animation_frame: self.animation_frame,
+            transition_symbols: self.transition_symbols.into_iter().map(owned_cow).collect(),
+            color_scheme: self.color_scheme,
+            auto_color: self.auto_color,
+            monochrome: self.monochrome,
+            bidi: self.bidi,
+            full_width_hit: self.full_width_hit,
+            rtl: self.rtl,
+            focus_corners: self.focus_corners,
+            double_width_label: self.double_width_label,
+            content_width: self.content_width,
+            right_hint: self.right_hint.map(owned_span),
+            prefix: self.prefix.map(owned_span),
+            suffix: self.suffix.map(owned_span),
+            inline_icons: self
+                .inline_icons
+                .into_iter()
+                .map(|(token, icon)| (token, owned_span(icon)))
+                .collect(),
+            inline_symbol: self.inline_symbol,
+            mnemonic: self.mnemonic,
+            scroll: self.scroll,
+            accessible_label: self.accessible_label,
+            indent: self.indent,
+            tree_guides: self.tree_guides,
+            guide_style: self.guide_style,
+            clip_indicator: self.clip_indicator,
+            disabled: self.disabled,
+            disabled_style: self.disabled_style,
+            required: self.required,
+            required_style: self.required_style,
+        }
+    }
+}
+
+impl Checkbox<'static> {
+    /// Creates a new checkbox that owns its label, so it borrows nothing.
+    ///
+    /// A `Checkbox<'static>` can be kept in long-lived application state, such as a
+    /// `Vec<Checkbox<'static>>` field, and rendered later. The builder methods keep the `'static`
+    /// lifetime when given owned or `'static` values, such as a `String` symbol.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tui_checkbox::Checkbox;
+    ///
+    /// struct App {
+    ///     options: Vec<Checkbox<'static>>,
+    /// }
+    ///
+    /// let name = String::from("Enable feature");
+    /// let app = App {
+    ///     options: vec![Checkbox::owned(name, true).checked_symbol(String::from("[x]"))],
+    /// };
+    /// ```
+    #[must_use]
+    pub fn owned(label: String, checked: bool) -> Self {
+        Self::new(label, checked)
+    }
+}
+
+/// Creates a checkbox for each `(label, checked)` pair, in order.
+///
+/// This saves mapping each pair through [`Checkbox::new`] when building a column of checkboxes.
+///
+/// # Examples
+///
+/// ```
+/// use tui_checkbox::{checkbox_list, Checkbox};
+///
+/// let checkboxes = checkbox_list([("Notifications", true), ("Auto-save", false)]);
+/// assert_eq!(checkboxes[0], Checkbox::new("Notifications", true));
+/// ```
+pub fn checkbox_list<'a, I, T>(items: I) -> Vec<Checkbox<'a>>
+where
+    I: IntoIterator<Item = (T, bool)>,
+    T: Into<Line<'a>>,
+{
+    items
+        .into_iter()
+        .map(|(label, checked)| Checkbox::new(label, checked))
+        .collect()
+}
+
+/// Generates shorthand methods that set the label foreground color.
+macro_rules! label_color_methods {
+    ($($name:ident => $color:ident),* $(,)?) => {
+        $(
+            #[doc = concat!(
+                "Sets the label foreground to [`Color::", stringify!($color), "`].\n\n",
+                "This is a shorthand for patching [`Checkbox::label_style`], mirroring the ",
+                "[`Stylize`] helpers.\n\n",
+                "[`Stylize`]: ratatui::style::Stylize"
+            )]
+            #[must_use = "method moves the value of self and returns the modified value"]
+            pub const fn $name(mut self) -> Self {
+                self.label_style = self.label_style.fg(Color::$color);
+                self
+            }
+        )*
+    };
+}
+
+/// Generates shorthand methods that add a modifier to the label.
+macro_rules! label_modifier_methods {
+    ($($name:ident => $modifier:ident),* $(,)?) => {
+        $(
+            #[doc = concat!(
+                "Adds [`Modifier::", stringify!($modifier), "`] to the label.\n\n",
+                "This is a shorthand for patching [`Checkbox::label_style`], mirroring the ",
+                "[`Stylize`] helpers.\n\n",
+                "[`Stylize`]: ratatui::style::Stylize"
+            )]
+            #[must_use = "method moves the value of self and returns the modified value"]
+            pub const fn $name(mut self) -> Self {
+                self.label_style = self.label_style.add_modifier(Modifier::$modifier);
+                self
+            }
+        )*
+    };
+}
+
+/// Label-only styling shorthands.
+///
+/// # Examples
+///
+/// ```
+/// use tui_checkbox::Checkbox;
+///
+/// let checkbox = Checkbox::new("Option", true).label_green().label_bold();
+/// ```
+impl Checkbox<'_> {
+    label_color_methods! {
+        label_black => Black,
+        label_red => Red,
+        label_green => Green,
+        label_yellow => Yellow,
+        label_blue => Blue,
+        label_magenta => Magenta,
+        label_cyan => Cyan,
+        label_gray => Gray,
+        label_dark_gray => DarkGray,
+        label_white => White,
+    }
+
+    label_modifier_methods! {
+        label_bold => BOLD,
+        label_dim => DIM,
+        label_italic => ITALIC,
+        label_underlined => UNDERLINED,
+        label_reversed => REVERSED,
+        label_crossed_out => CROSSED_OUT,
+    }
+}
+
+impl Styled for Checkbox<'_> {
+    type Item = Self;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style<S: Into<Style>>(mut self, style: S) -> Self::Item {
+        self.style = style.into();
+        self
+    }
+}
+
+impl<'a> From<&'a str> for Checkbox<'a> {
+    /// Creates an unchecked checkbox with the given label.
+    fn from(label: &'a str) -> Self {
+        Self::new(label, false)
+    }
+}
+
+impl From<String> for Checkbox<'_> {
+    /// Creates an unchecked checkbox with the given label.
+    fn from(label: String) -> Self {
+        Self::new(label, false)
+    }
+}
+
+impl<'a> From<Cow<'a, str>> for Checkbox<'a> {
+    /// Creates an unchecked checkbox with the given label.
+    fn from(label: Cow<'a, str>) -> Self {
+        Self::new(label, false)
+    }
+}
+
+impl Widget for Checkbox<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        Widget::render(&self, area, buf);
+    }
+}
+
+impl Widget for &Checkbox<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        self.render_with_cache(area, buf, None);
+    }
+}
+
+impl Checkbox<'_> {
+    /// Renders the checkbox, reusing the wrapped label lines kept in `cache` when there is one.
+    fn render_with_cache(&self, area: Rect, buf: &mut Buffer, cache: Option<&mut WrapCache>) {
+        let style = self.render_style(self.style);
+        if self.style_scope == StyleScope::All {
+            buf.set_style(area, style);
+        }
+        let inner = if let Some(ref block) = self.block {
             let inner_area = block.inner(area);
-            block.render(area, buf);
+            self.render_block(block, area, buf);
+            if self.checked {
+                let checked_style = self.render_style(self.block_checked_style);
+                for position in area.positions().filter(|p| !inner_area.contains(*p)) {
+                    buf[position].set_style(checked_style);
+                }
+            }
             inner_area
         } else {
-            area
+            area
+        };
+        if self.style_scope == StyleScope::Inner {
+            buf.set_style(inner, style);
+        }
+        let inner = if self.focus_corners {
+            self.render_focus_corners(inner, buf);
+            inner.inner(Margin::new(1, 1))
+        } else {
+            inner
+        };
+        self.render_checkbox(inner, buf, cache);
+    }
+
+    /// Renders `block` into `area`, keeping the colors already in the area in monochrome mode.
+    fn render_block(&self, block: &Block<'_>, area: Rect, buf: &mut Buffer) {
+        if !self.monochrome {
+            block.render(area, buf);
+            return;
+        }
+        // The styles of a block can't be read back, so the colors it paints are undone instead
+        let area = area.intersection(buf.area);
+        let colors: Vec<_> = area.positions().map(|p| (buf[p].fg, buf[p].bg)).collect();
+        block.render(area, buf);
+        for (position, (fg, bg)) in area.positions().zip(colors) {
+            buf[position].fg = fg;
+            buf[position].bg = bg;
+        }
+    }
+
+    /// Returns `style` without its colors in monochrome mode, or unchanged otherwise.
+    const fn render_style(&self, style: Style) -> Style {
+        if self.monochrome {
+            Style {
+                fg: None,
+                bg: None,
+                ..style
+            }
+        } else {
+            style
+        }
+    }
+
+    /// Removes the colors of every span of `lines` in monochrome mode.
+    fn remove_colors(&self, lines: &mut [Line<'_>]) {
+        if !self.monochrome {
+            return;
+        }
+        for span in lines.iter_mut().flat_map(|line| line.spans.iter_mut()) {
+            span.style = self.render_style(span.style);
+        }
+    }
+
+    /// Returns the focus style for the current frame, if the checkbox is focused.
+    fn current_focus_style(&self) -> Option<Style> {
+        if !self.focused || self.focus_style_frames.is_empty() {
+            return None;
+        }
+        Some(self.focus_style_frames[self.frame % self.focus_style_frames.len()])
+    }
+
+    /// Returns the symbol to render for the current state.
+    fn current_symbol(&self) -> Cow<'_, str> {
+        let animated =
+            self.checked && !self.pending && !self.indeterminate && self.partial.is_none();
+        match self.animation_symbol().filter(|_| animated) {
+            Some(frame) => Cow::Owned(frame),
+            None => self.symbol().into(),
+        }
+    }
+
+    /// Returns the partially filled symbol for the current frame of the check animation, if it
+    /// is running.
+    fn animation_symbol(&self) -> Option<String> {
+        let frames = self.check_animation?;
+        let frame = self.animation_frame.filter(|&frame| frame < frames)?;
+        let cells = Span::raw(self.checked_symbol.as_ref()).width().max(1);
+
+        // Each cell steps through the shades in turn, so the fill advances left to right
+        let steps = symbols::SHADES.len();
+        let filled = (cells * steps * usize::from(frame)).div_ceil(usize::from(frames));
+        let symbol = (0..cells)
+            .map(|cell| match filled.saturating_sub(cell * steps) {
+                0 => " ",
+                level => symbols::SHADES[level.min(steps) - 1],
+            })
+            .collect();
+        Some(symbol)
+    }
+
+    /// Draws the focus corner marks in the corners of `area` when focused.
+    fn render_focus_corners(&self, area: Rect, buf: &mut Buffer) {
+        if !self.focused || area.width < 2 || area.height < 2 {
+            return;
+        }
+        let style = self.render_style(self.style.patch(self.checkbox_style));
+        let (left, right) = (area.left(), area.right() - 1);
+        let (top, bottom) = (area.top(), area.bottom() - 1);
+        buf.set_string(left, top, symbols::FOCUS_TOP_LEFT, style);
+        buf.set_string(right, top, symbols::FOCUS_TOP_RIGHT, style);
+        buf.set_string(left, bottom, symbols::FOCUS_BOTTOM_LEFT, style);
+        buf.set_string(right, bottom, symbols::FOCUS_BOTTOM_RIGHT, style);
+    }
+
+    /// Returns the combined styles of the checkbox symbol and the label.
+    fn content_styles(&self) -> (Style, Style) {
+        let (mut checkbox_style, mut label_style) = (self.style, self.style);
+        if let Some(scheme) = self.color_scheme {
+            let symbol_fg = if self.checked {
+                scheme.checked_fg
+            } else {
+                scheme.unchecked_fg
+            };
+            checkbox_style = checkbox_style.fg(symbol_fg);
+            label_style = label_style.fg(scheme.label_fg);
+        }
+        if self.auto_color {
+            let symbol_fg = if self.checked {
+                Color::Green
+            } else {
+                Color::DarkGray
+            };
+            checkbox_style = checkbox_style.fg(symbol_fg);
+        }
+        checkbox_style = checkbox_style.patch(self.checkbox_style);
+        label_style = label_style.patch(self.label_style);
+        if self.strikethrough_when_checked && self.checked && !self.indeterminate {
+            label_style = label_style.add_modifier(Modifier::CROSSED_OUT);
+        }
+        if self.disabled {
+            let disabled_style = self.disabled_style.unwrap_or_else(|| {
+                let fg = self
+                    .color_scheme
+                    .map_or(Color::DarkGray, |scheme| scheme.disabled_fg);
+                Style::default().fg(fg).add_modifier(Modifier::DIM)
+            });
+            checkbox_style = checkbox_style.patch(disabled_style);
+            label_style = label_style.patch(disabled_style);
+        }
+        if let Some(scheme) = self.color_scheme.filter(|_| self.focused) {
+            checkbox_style = checkbox_style.bg(scheme.focus_bg);
+            label_style = label_style.bg(scheme.focus_bg);
+        }
+        if self.focused {
+            checkbox_style = checkbox_style.patch(self.focus_style);
+            label_style = label_style.patch(self.focus_style);
+        }
+        if let Some(focus_style) = self.current_focus_style() {
+            checkbox_style = checkbox_style.patch(focus_style);
+            label_style = label_style.patch(focus_style);
+        }
+        (
+            self.render_style(checkbox_style),
+            self.render_style(label_style),
+        )
+    }
+
+    /// Returns an owned copy of the label lines with `label_style` patched onto each span, so the
+    /// style survives the span-level wrapping and truncation. An empty label has one empty line.
+    fn styled_label(&self, label_style: Style) -> Vec<Line<'_>> {
+        let text_style = label_style.patch(self.label.style);
+        let lines = self.label.lines.iter().map(|line| {
+            let line_style = text_style.patch(line.style);
+            let spans = line
+                .spans
+                .iter()
+                .map(|s| Span::styled(self.sanitize(&s.content), line_style.patch(s.style)));
+            Line::from(self.substitute_icons(spans.collect()))
+        });
+        let mut lines: Vec<_> = lines.collect();
+        if lines.is_empty() {
+            lines.push(Line::default());
+        }
+        self.transform_case(&mut lines);
+        if let Some(key) = self.mnemonic {
+            Self::underline_mnemonic(&mut lines, key);
+        }
+        if self.has_inline_symbol() {
+            let (checkbox_style, _) = self.content_styles();
+            self.substitute_marker(&mut lines, checkbox_style);
+        }
+        if !self.is_valid() {
+            lines[0].spans.splice(
+                0..0,
+                [
+                    Span::styled("*", text_style.patch(self.required_style)),
+                    Span::styled(" ", text_style),
+                ],
+            );
+        }
+        // The fill and the value end the first line, and are split off again when the label is
+        // laid out
+        if let Some(value) = &self.value {
+            let value_style = text_style.patch(value.style);
+            lines[0].spans.extend([
+                Span::styled("", text_style),
+                Span::styled(self.sanitize(&value.content), value_style),
+            ]);
+        }
+        self.remove_colors(&mut lines);
+        lines
+    }
+
+    /// Expands the tabs of `text` and drops its other control characters, borrowing `text` when
+    /// it has none.
+    fn sanitize<'t>(&self, text: &'t str) -> Cow<'t, str> {
+        if !text.contains(char::is_control) {
+            return Cow::Borrowed(text);
+        }
+        let tab = " ".repeat(usize::from(self.tab_width));
+        let mut sanitized = String::with_capacity(text.len());
+        for c in text.chars() {
+            match c {
+                '\t' => sanitized.push_str(&tab),
+                c if c.is_control() => {}
+                c => sanitized.push(c),
+            }
+        }
+        Cow::Owned(sanitized)
+    }
+
+    /// Changes the letter case of `lines` to the label case, keeping a word that continues across
+    /// spans a single word.
+    fn transform_case(&self, lines: &mut [Line<'_>]) {
+        if self.label_case == LabelCase::None {
+            return;
+        }
+        for line in lines {
+            let mut word_start = true;
+            for span in &mut line.spans {
+                let content = match self.label_case {
+                    LabelCase::Upper => span.content.to_uppercase(),
+                    LabelCase::Lower => span.content.to_lowercase(),
+                    LabelCase::Title | LabelCase::None => {
+                        let mut content = String::with_capacity(span.content.len());
+                        for c in span.content.chars() {
+                            if c.is_whitespace() {
+                                content.push(c);
+                            } else if word_start {
+                                content.extend(c.to_uppercase());
+                            } else {
+                                content.extend(c.to_lowercase());
+                            }
+                            word_start = c.is_whitespace();
+                        }
+                        content
+                    }
+                };
+                span.content = content.into();
+            }
+        }
+    }
+
+    /// Underlines the first character of `lines` matching `key`, splitting its span.
+    fn underline_mnemonic(lines: &mut [Line<'_>], key: char) {
+        for line in lines {
+            for (index, span) in line.spans.iter().enumerate() {
+                let Some((start, c)) = span
+                    .content
+                    .char_indices()
+                    .find(|&(_, c)| chars_match(c, key))
+                else {
+                    continue;
+                };
+                let end = start + c.len_utf8();
+                let underlined = span.style.add_modifier(Modifier::UNDERLINED);
+                let pieces = [
+                    Span::styled(span.content[..start].to_string(), span.style),
+                    Span::styled(span.content[start..end].to_string(), underlined),
+                    Span::styled(span.content[end..].to_string(), span.style),
+                ];
+                line.spans.splice(
+                    index..=index,
+                    pieces.into_iter().filter(|p| !p.content.is_empty()),
+                );
+                return;
+            }
+        }
+    }
+
+    /// Returns whether the symbol is drawn in place of the marker in the label.
+    fn has_inline_symbol(&self) -> bool {
+        self.inline_symbol
+            && self
+                .label
+                .lines
+                .iter()
+                .flat_map(|line| &line.spans)
+                .any(|span| span.content.contains(symbols::INLINE_MARKER))
+    }
+
+    /// Replaces the first inline marker in `lines` with the current symbol, splitting its span.
+    fn substitute_marker(&self, lines: &mut [Line<'_>], checkbox_style: Style) {
+        for line in lines {
+            for (index, span) in line.spans.iter().enumerate() {
+                let Some(start) = span.content.find(symbols::INLINE_MARKER) else {
+                    continue;
+                };
+                let end = start + symbols::INLINE_MARKER.len();
+                let symbol = self
+                    .symbol_line(checkbox_style)
+                    .spans
+                    .into_iter()
+                    .map(|s| Span::styled(s.content.into_owned(), s.style));
+                let pieces: Vec<_> = [Span::styled(span.content[..start].to_string(), span.style)]
+                    .into_iter()
+                    .chain(symbol)
+                    .chain([Span::styled(span.content[end..].to_string(), span.style)])
+                    .filter(|p| !p.content.is_empty())
+                    .collect();
+                line.spans.splice(index..=index, pieces);
+                return;
+            }
+        }
+    }
+
+    /// Replaces the inline icon tokens in `spans` with their icons.
+    fn substitute_icons<'l>(&self, mut spans: Vec<Span<'l>>) -> Vec<Span<'l>> {
+        for (token, icon) in &self.inline_icons {
+            if token.is_empty() {
+                continue;
+            }
+            spans = spans
+                .into_iter()
+                .flat_map(|span| {
+                    let mut pieces = Vec::new();
+                    for (i, text) in span.content.split(token.as_str()).enumerate() {
+                        if i > 0 {
+                            let style = span.style.patch(icon.style);
+                            pieces.push(Span::styled(icon.content.to_string(), style));
+                        }
+                        if !text.is_empty() {
+                            pieces.push(Span::styled(text.to_string(), span.style));
+                        }
+                    }
+                    pieces
+                })
+                .collect();
+        }
+        spans
+    }
+
+    /// Returns the width taken next to the label in horizontal positions: the symbol, the gap
+    /// between the symbol and the label, and the pill caps.
+    fn horizontal_overhead(&self, checkbox_width: u16) -> u16 {
+        checkbox_width + self.gap_width() + 2 * u16::from(self.pill)
+    }
+
+    /// Returns the width between the symbol and a label beside it.
+    fn gap_width(&self) -> u16 {
+        if self.has_inline_symbol() {
+            return 0;
+        }
+        self.separator
+            .as_ref()
+            .map_or(self.spacing, |separator| separator.width() as u16)
+    }
+
+    /// Resolves where each part of the checkbox goes within `area`, inside the block and the
+    /// focus corner margin. Returns the constrained content area along with the placement, or
+    /// `None` when no space is left for the content.
+    fn layout(&self, area: Rect) -> Option<(Rect, Placement<'_>)> {
+        let mut inner = self.block.as_ref().map_or(area, |block| block.inner(area));
+        if self.focus_corners {
+            inner = inner.inner(Margin::new(1, 1));
+        }
+        if inner.is_empty() {
+            return None;
+        }
+
+        let checkbox_width = self.symbol_column_width();
+        let render_area = self.constrained_area(inner);
+        let (main_area, _) = self.split_sub_rows(render_area);
+        let placement = self.place(
+            main_area,
+            checkbox_width,
+            self.styled_label(Style::default()),
+            None,
+        );
+        Some((render_area, placement))
+    }
+
+    /// Returns the rect covering the placed content, widened to the full width of `render_area`
+    /// with [`Checkbox::full_width_hit`].
+    fn content_bounds(&self, render_area: Rect, placement: &Placement) -> Option<Rect> {
+        let bounds = placement.bounds()?;
+        Some(if self.full_width_hit {
+            Rect {
+                x: render_area.x,
+                width: render_area.width,
+                ..bounds
+            }
+        } else {
+            bounds
+        })
+    }
+
+    /// Returns the horizontal and vertical space taken around the content by the block and the
+    /// focus corner margin.
+    fn chrome_size(&self) -> (u16, u16) {
+        let (mut width, mut height) = (0, 0);
+        if let Some(ref block) = self.block {
+            let probe = Rect::new(0, 0, u16::MAX / 2, u16::MAX / 2);
+            let inner = block.inner(probe);
+            width += probe.width - inner.width;
+            height += probe.height - inner.height;
+        }
+        if self.focus_corners {
+            width += 2;
+            height += 2;
+        }
+        (width, height)
+    }
+
+    /// Returns the label position as drawn, mirrored horizontally in right-to-left mode. An
+    /// inline symbol leaves the label on the right of an empty symbol column.
+    fn visual_label_position(&self) -> LabelPosition {
+        if self.has_inline_symbol() {
+            return LabelPosition::Right;
+        }
+        match self.label_position {
+            LabelPosition::Right if self.rtl => LabelPosition::Left,
+            LabelPosition::Left if self.rtl => LabelPosition::Right,
+            position => position,
+        }
+    }
+
+    /// Returns the horizontal alignment as drawn, mirrored in right-to-left mode.
+    const fn visual_horizontal_alignment(&self) -> HorizontalAlignment {
+        self.mirrored(self.horizontal_alignment)
+    }
+
+    /// Returns the alignment of the symbol as drawn, which follows the horizontal alignment
+    /// unless the symbol has one of its own.
+    const fn visual_symbol_alignment(&self) -> HorizontalAlignment {
+        match self.symbol_alignment {
+            Some(alignment) => self.mirrored(alignment),
+            None => self.visual_horizontal_alignment(),
+        }
+    }
+
+    /// Returns the width of the column the symbol is placed in.
+    fn symbol_column_width(&self) -> u16 {
+        if self.has_inline_symbol() {
+            return 0;
+        }
+        let width = self.symbol_line(Style::default()).width() as u16;
+        self.symbol_width.map_or(width, |column| column.max(width))
+    }
+
+    /// Returns the current symbol in `checkbox_style`, between the sides of its frame, if any.
+    fn symbol_line(&self, checkbox_style: Style) -> Line<'_> {
+        let symbol = self.current_symbol();
+        let mut spans = match &self.checked_symbol_line {
+            // The pre-styled symbol stands in for the checked symbol wherever it is shown
+            Some(line) if self.checked && symbol == self.checked_symbol => {
+                let line_style = checkbox_style.patch(line.style);
+                line.spans
+                    .iter()
+                    .map(|s| Span::styled(s.content.as_ref(), line_style.patch(s.style)))
+                    .collect()
+            }
+            _ => vec![Span::styled(symbol, checkbox_style)],
+        };
+        if let Some((left, right)) = &self.symbol_frame {
+            let frame_style = checkbox_style.patch(self.frame_style);
+            spans.insert(0, Span::styled(left.as_ref(), frame_style));
+            spans.push(Span::styled(right.as_ref(), frame_style));
+        }
+        let mut line = Line::from(spans);
+        self.remove_colors(std::slice::from_mut(&mut line));
+        line
+    }
+
+    /// Mirrors `alignment` in right-to-left mode.
+    const fn mirrored(&self, alignment: HorizontalAlignment) -> HorizontalAlignment {
+        match alignment {
+            HorizontalAlignment::Left if self.rtl => HorizontalAlignment::Right,
+            HorizontalAlignment::Right if self.rtl => HorizontalAlignment::Left,
+            alignment => alignment,
+        }
+    }
+
+    /// Aligns the symbol across the whole `width` and the label within the space left beside
+    /// it, returning the symbol column, the label column and the label width.
+    fn symbol_columns(
+        &self,
+        width: u16,
+        checkbox_width: u16,
+        space_width: u16,
+        max_label_width: u16,
+    ) -> (u16, u16, u16) {
+        let checkbox_x = align_offset(self.visual_symbol_alignment(), width, checkbox_width);
+        let (start, end) = if self.visual_label_position() == LabelPosition::Left {
+            (0, checkbox_x.saturating_sub(space_width))
+        } else {
+            (
+                (checkbox_x + checkbox_width + space_width).min(width),
+                width,
+            )
+        };
+        let alignment = self.visual_horizontal_alignment();
+        let label_x = start + align_offset(alignment, end - start, max_label_width);
+        (checkbox_x, label_x, end - start)
+    }
+
+    /// Applies the min and max width constraints to `area`, never growing it past its own width.
+    fn constrained_area(&self, area: Rect) -> Rect {
+        let mut render_area = area;
+        if let Some(min_width) = self.min_width {
+            render_area.width = render_area.width.max(min_width);
+        }
+        if let Some(max_width) = self.max_width {
+            render_area.width = render_area.width.min(max_width);
+        }
+        render_area.width = render_area.width.min(area.width);
+        render_area
+    }
+
+    /// Splits `area` into the rows of the main checkbox and the rows reserved for sub-options,
+    /// which take one row each below it while keeping at least one row for the checkbox.
+    fn split_sub_rows(&self, area: Rect) -> (Rect, u16) {
+        let sub_rows = (self.sub_options.len() as u16).min(area.height.saturating_sub(1));
+        let main_area = Rect {
+            height: area.height - sub_rows,
+            ..area
+        };
+        (main_area, sub_rows)
+    }
+
+    fn render_checkbox(&self, area: Rect, buf: &mut Buffer, cache: Option<&mut WrapCache>) {
+        if area.is_empty() {
+            return;
+        }
+
+        let (checkbox_style, label_style) = self.content_styles();
+        let render_area = self.constrained_area(area);
+
+        // Create checkbox and label spans
+        let checkbox_line = self.symbol_line(checkbox_style);
+        let checkbox_width = self.symbol_column_width();
+        let styled_label = self.styled_label(label_style);
+
+        let (main_area, _) = self.split_sub_rows(render_area);
+        let placement = self.place(main_area, checkbox_width, styled_label, cache);
+
+        if self.full_width_hit {
+            if let Some(bounds) = self.content_bounds(render_area, &placement) {
+                buf.set_style(bounds, self.render_style(self.style));
+            }
+        }
+
+        if !placement.symbol.is_empty() {
+            let alignment = self.mirrored(self.symbol_alignment.unwrap_or_default());
+            let offset = align_offset(
+                alignment,
+                placement.symbol.width,
+                checkbox_line.width() as u16,
+            );
+            let symbol_area = Rect {
+                x: placement.symbol.x + offset,
+                width: placement.symbol.width - offset,
+                ..placement.symbol
+            };
+            checkbox_line.render(symbol_area, buf);
+        }
+        // The gap takes the label background, so a highlighted label reaches up to the symbol
+        if let Some(separator) = &self.separator {
+            Span::styled(separator.as_ref(), label_style).render(placement.separator, buf);
+        } else if let Some(bg) = label_style.bg {
+            buf.set_style(placement.separator, Style::new().bg(bg));
+        }
+        for (label_area, label_line) in &placement.label {
+            label_line.render(*label_area, buf);
+            if self.fade_overflow
+                && !self.wrap_label
+                && label_line.width() > label_area.width.into()
+            {
+                let cells = label_area.width.min(FADE_CELLS);
+                let fade_area = Rect {
+                    x: label_area.right() - cells,
+                    width: cells,
+                    ..*label_area
+                };
+                buf.set_style(fade_area, Modifier::DIM);
+            }
+        }
+        if let Some(hint) = &self.right_hint {
+            let style = self.render_style(hint.style);
+            Line::from(hint.clone().style(style)).render(placement.hint, buf);
+        }
+        if let Some(prefix) = &self.prefix {
+            let style = self.render_style(prefix.style);
+            prefix.clone().style(style).render(placement.prefix, buf);
+        }
+        if self.tree_guides {
+            self.render_guides(placement.guide, buf);
+        }
+        if let Some(suffix) = &self.suffix {
+            let style = self.render_style(suffix.style);
+            suffix.clone().style(style).render(placement.suffix, buf);
+        }
+        if let Some(pill_area) = placement.pill {
+            self.render_pill(pill_area, buf);
+        }
+        if self.clip_indicator
+            && self.min_width.is_some_and(|min| min > area.width)
+            && !render_area.is_empty()
+        {
+            let row = placement.first_row(main_area);
+            buf.set_string(
+                render_area.right() - 1,
+                row,
+                symbols::CLIP_INDICATOR,
+                checkbox_style,
+            );
+        }
+
+        if !self.sub_options.is_empty() {
+            let sub_area = Rect {
+                y: render_area.y + placement.used_rows,
+                height: render_area.height - placement.used_rows,
+                ..render_area
+            };
+            self.render_sub_options(sub_area, checkbox_width + 1, buf);
+        }
+    }
+
+    /// Draws the tree guides of each indentation level into `area`, cut at its right edge.
+    fn render_guides(&self, area: Rect, buf: &mut Buffer) {
+        let guides = (1..self.indent)
+            .map(|_| symbols::TREE_PIPE)
+            .chain([symbols::TREE_BRANCH]);
+        let mut x = area.x;
+        for guide in guides {
+            if x >= area.right() {
+                break;
+            }
+            (x, _) = buf.set_stringn(
+                x,
+                area.y,
+                guide,
+                (area.right() - x).into(),
+                self.render_style(self.guide_style),
+            );
+        }
+    }
+
+    /// Returns the width of the indentation, limited to `max_width`.
+    fn guide_width(&self, max_width: u16) -> u16 {
+        self.indent
+            .saturating_mul(symbols::TREE_BRANCH.chars().count() as u16)
+            .min(max_width)
+    }
+
+    /// Returns the widths of the prefix and suffix, or zero for both when they don't fit in
+    /// `available` columns together with the symbol.
+    fn decoration_widths(&self, available: u16, checkbox_width: u16) -> (u16, u16) {
+        let width = |span: &Option<Span>| span.as_ref().map_or(0, |span| span.width() as u16);
+        let (prefix, suffix) = (width(&self.prefix), width(&self.suffix));
+        if prefix + suffix + checkbox_width > available {
+            (0, 0)
+        } else {
+            (prefix, suffix)
+        }
+    }
+
+    /// Returns the width reserved for the right hint and its gap, limited to `max_width`.
+    fn hint_overhead(&self, max_width: u16) -> u16 {
+        self.right_hint
+            .as_ref()
+            .map_or(0, |hint| (hint.width() as u16 + 1).min(max_width))
+    }
+
+    /// Resolves where the symbol, the label lines and the right hint go within `area`.
+    fn place<'l>(
+        &self,
+        area: Rect,
+        checkbox_width: u16,
+        label: Vec<Line<'l>>,
+        cache: Option<&'l mut WrapCache>,
+    ) -> Placement<'l> {
+        if area.is_empty() {
+            return Placement::default();
+        }
+
+        // The indentation keeps its columns at the left edge and the hint at the right edge, and
+        // the content is laid out in the rest, less the columns of the prefix and suffix
+        let guide_width = self.guide_width(area.width);
+        let hint_width = self.hint_overhead(area.width - guide_width);
+        let (prefix_width, suffix_width) =
+            self.decoration_widths(area.width - guide_width - hint_width, checkbox_width);
+        let content_area = Rect {
+            x: area.x + guide_width + prefix_width,
+            width: area.width - guide_width - hint_width - prefix_width - suffix_width,
+            ..area
+        };
+        let mut placement = match self.visual_label_position() {
+            LabelPosition::Right | LabelPosition::Left => {
+                self.place_horizontal(content_area, checkbox_width, label, cache)
+            }
+            LabelPosition::Top | LabelPosition::Bottom => {
+                self.place_vertical(content_area, checkbox_width, label, cache)
+            }
+        };
+
+        // The decorations hug the content on the row of the symbol
+        if let Some(bounds) = placement.bounds() {
+            let row = placement.first_row(area);
+            placement.prefix = Rect::new(bounds.x - prefix_width, row, prefix_width, 1);
+            placement.suffix = Rect::new(bounds.right(), row, suffix_width, 1);
+            placement.guide = Rect::new(area.x, row, guide_width, 1);
+        }
+
+        if hint_width > 0 {
+            let row = placement.first_row(area);
+            let width = self
+                .right_hint
+                .as_ref()
+                .map_or(0, |hint| hint.width() as u16)
+                .min(area.width);
+            placement.hint = Rect::new(area.right() - width, row, width, 1);
+            placement.used_rows = placement.used_rows.max(row - area.y + 1);
+        }
+        placement
+    }
+
+    fn place_horizontal<'l>(
+        &self,
+        area: Rect,
+        checkbox_width: u16,
+        label: Vec<Line<'l>>,
+        cache: Option<&'l mut WrapCache>,
+    ) -> Placement<'l> {
+        // The pill adds a rounded cap on each side of the content
+        let pill_padding = u16::from(self.pill);
+
+        // A fixed content width replaces the area as the space the label is laid out in
+        let fixed_width = self
+            .content_width
+            .map(|width| width.min(area.width.saturating_sub(2 * pill_padding)));
+
+        // Handle wrapping if enabled
+        let available_width = fixed_width
+            .map_or(area.width, |width| width + 2 * pill_padding)
+            .saturating_sub(self.horizontal_overhead(checkbox_width));
+        let label_lines = self.label_lines(label, available_width, cache);
+
+        let max_label_width = max_line_width(&label_lines);
+        // An empty label leaves no gap next to the symbol
+        let space_width = if max_label_width > 0 {
+            self.gap_width()
+        } else {
+            0
+        };
+        let content_width = fixed_width.unwrap_or(checkbox_width + space_width + max_label_width);
+        let total_width = content_width + 2 * pill_padding;
+
+        // Calculate horizontal offset based on alignment
+        let pill_x = align_offset(self.visual_horizontal_alignment(), area.width, total_width);
+        let x_offset = pill_x + pill_padding;
+
+        // Calculate vertical offset based on alignment, counting only the rows left after
+        // scrolling
+        let scroll = self.scroll;
+        let full_height = label_lines.len() as u16;
+        let content_height = full_height.saturating_sub(scroll);
+        let y_offset = match self.vertical_alignment {
+            VerticalAlignment::Top => 0,
+            VerticalAlignment::Center => area.height.saturating_sub(content_height) / 2,
+            VerticalAlignment::Bottom => area.height.saturating_sub(content_height),
+        };
+
+        // Left and Right only differ in column order, so both share the same rows. A fixed
+        // content width pins the label to its left edge and the checkbox to its right edge, or
+        // the other way around in right-to-left mode. The checkbox scrolls out with its row.
+        let symbol_row = self.symbol_row(full_height);
+        let checkbox_y = symbol_row.checked_sub(scroll).map(|row| y_offset + row);
+        let (checkbox_x, label_x, label_width) = match (fixed_width, self.visual_label_position()) {
+            (None, _) if self.symbol_alignment.is_some() => {
+                self.symbol_columns(area.width, checkbox_width, space_width, max_label_width)
+            }
+            (Some(width), _) if self.rtl => (
+                x_offset,
+                x_offset + checkbox_width + space_width,
+                width.saturating_sub(checkbox_width + space_width),
+            ),
+            (Some(width), _) => (
+                x_offset + width.saturating_sub(checkbox_width),
+                x_offset,
+                width.saturating_sub(checkbox_width + space_width),
+            ),
+            (None, LabelPosition::Left) => {
+                // The symbol's cells are reserved first and the label gets the rest, so a label
+                // wider than the area cannot push the symbol out of it
+                let label_width = max_label_width.min(
+                    area.width
+                        .saturating_sub(x_offset + checkbox_width + space_width),
+                );
+                (x_offset + label_width + space_width, x_offset, label_width)
+            }
+            (None, _) => (x_offset, x_offset + checkbox_width + space_width, u16::MAX),
+        };
+
+        let mut placement = Placement {
+            used_rows: (y_offset + content_height).min(area.height),
+            ..Placement::default()
+        };
+        if y_offset >= area.height {
+            return placement;
+        }
+
+        if let Some(checkbox_y) = checkbox_y {
+            placement.symbol = row_rect(area, checkbox_x, checkbox_y, checkbox_width);
+            let separator_x = if label_x < checkbox_x {
+                checkbox_x.saturating_sub(space_width)
+            } else {
+                checkbox_x + checkbox_width
+            };
+            placement.separator = row_rect(area, separator_x, checkbox_y, space_width);
+        }
+
+        // Right-to-left lines are aligned to the right edge of the label block
+        let block_width = label_width.min(fixed_width.map_or(max_label_width, |_| label_width));
+        for (i, label_line) in label_lines.into_iter().skip(scroll.into()).enumerate() {
+            let label_y = y_offset + i as u16;
+            if label_y >= area.height {
+                break;
+            }
+            let line_width = (label_line.width() as u16).min(label_width);
+            let line_x = if self.rtl {
+                label_x + block_width.saturating_sub(line_width)
+            } else {
+                label_x
+            };
+            if line_x < area.width {
+                let label_area = row_rect(area, line_x, label_y, line_width);
+                placement.label.push((label_area, label_line));
+            }
+        }
+
+        if self.pill && content_height > 0 {
+            let pill_area = Rect {
+                x: area.x + pill_x,
+                y: area.y + y_offset,
+                width: total_width,
+                height: content_height,
+            }
+            .intersection(area);
+            placement.pill = Some(pill_area);
+        }
+
+        placement
+    }
+
+    /// Returns the row of the symbol within a label block of `height` rows beside it.
+    ///
+    /// The symbol sits on the first row of the label block, unless the label has several lines
+    /// of its own, which center the symbol against them, or the symbol has a vertical alignment
+    /// of its own.
+    fn symbol_row(&self, height: u16) -> u16 {
+        let alignment = self
+            .symbol_vertical_alignment
+            .unwrap_or(if self.label.lines.len() > 1 {
+                VerticalAlignment::Center
+            } else {
+                VerticalAlignment::Top
+            });
+        let last_row = height.saturating_sub(1);
+        match alignment {
+            VerticalAlignment::Top => 0,
+            VerticalAlignment::Center => last_row / 2,
+            VerticalAlignment::Bottom => last_row,
+        }
+    }
+
+    /// Paints the pill highlight over `area`, drawing the rounded caps in its outer columns.
+    fn render_pill(&self, area: Rect, buf: &mut Buffer) {
+        let pill_style = self.render_style(self.pill_style);
+        buf.set_style(area, pill_style);
+        // The caps are drawn in the pill color on the terminal background, if there are colors
+        let cap_style = if self.monochrome {
+            Style::default()
+        } else {
+            Style::default()
+                .fg(self.pill_style.bg.unwrap_or_default())
+                .bg(Color::Reset)
+        };
+        for y in area.top()..area.bottom() {
+            if area.width >= 1 {
+                buf[(area.left(), y)]
+                    .set_symbol(symbols::PILL_LEFT)
+                    .set_style(cap_style);
+            }
+            if area.width >= 2 {
+                buf[(area.right() - 1, y)]
+                    .set_symbol(symbols::PILL_RIGHT)
+                    .set_style(cap_style);
+            }
+        }
+    }
+
+    fn place_vertical<'l>(
+        &self,
+        area: Rect,
+        checkbox_width: u16,
+        label: Vec<Line<'l>>,
+        cache: Option<&'l mut WrapCache>,
+    ) -> Placement<'l> {
+        // Handle wrapping if enabled. An empty label takes no rows, as it leaves no gap beside
+        // the symbol in horizontal positions
+        let mut label_lines = self.label_lines(label, area.width, cache);
+        if max_line_width(&label_lines) == 0 {
+            label_lines.clear();
+        }
+
+        let label_height = label_lines.len() as u16;
+        // checkbox + label lines, less the rows scrolled out
+        let total_height = (1 + label_height).saturating_sub(self.scroll);
+
+        // Calculate vertical offset
+        let y_offset = match self.vertical_alignment {
+            VerticalAlignment::Top => 0,
+            VerticalAlignment::Center => area.height.saturating_sub(total_height) / 2,
+            VerticalAlignment::Bottom => area.height.saturating_sub(total_height),
+        };
+
+        // The label sits above the checkbox for Top and below it for Bottom
+        let (checkbox_row, label_row) = match self.visual_label_position() {
+            LabelPosition::Top => (label_height, 0),
+            _ => (0, 1),
+        };
+
+        // Each row is aligned on its own, so the checkbox and every label line share an axis
+        // unless the symbol has an alignment of its own. Rows are counted from the top of the
+        // content and the ones scrolled out are left empty.
+        let alignment = self.visual_horizontal_alignment();
+        let row_area = |row: u16, width: u16, alignment: HorizontalAlignment| {
+            let Some(row) = row
+                .checked_sub(self.scroll)
+                .map(|row| y_offset + row)
+                .filter(|row| *row < area.height)
+            else {
+                return Rect::default();
+            };
+            let x_offset = align_offset(alignment, area.width, width);
+            Rect {
+                x: area.x + x_offset,
+                y: area.y + row,
+                width: width.min(area.width.saturating_sub(x_offset)),
+                height: 1,
+            }
+        };
+
+        let label: Vec<_> = label_lines
+            .into_iter()
+            .enumerate()
+            .map(|(i, line)| {
+                let width = line.width() as u16;
+                let label_area = row_area(label_row + i as u16, width, alignment);
+                (label_area, line)
+            })
+            .filter(|(label_area, _)| !label_area.is_empty())
+            .collect();
+
+        let mut symbol = row_area(checkbox_row, checkbox_width, self.visual_symbol_alignment());
+        let label_columns = label
+            .iter()
+            .map(|(label_area, _)| *label_area)
+            .reduce(Rect::union);
+        if let (SymbolAnchor::LabelEdge, Some(columns), false) =
+            (self.symbol_anchor, label_columns, symbol.is_empty())
+        {
+            let offset = align_offset(
+                self.visual_symbol_alignment(),
+                columns.width,
+                checkbox_width,
+            );
+            symbol.x = columns.x + offset;
+            symbol.width = checkbox_width.min(area.right() - symbol.x);
+        }
+
+        Placement {
+            symbol,
+            label,
+            used_rows: (y_offset + total_height).min(area.height),
+            ..Placement::default()
+        }
+    }
+
+    /// Renders the sub-options on the rows of `area`, indented so that their symbols line up
+    /// with the start of the main checkbox label.
+    fn render_sub_options(&self, area: Rect, indent: u16, buf: &mut Buffer) {
+        let rows = area.rows().take(self.sub_options.len());
+        for (row, (label, checked)) in rows.zip(&self.sub_options) {
+            let sub_area = Rect {
+                x: row.x + indent.min(row.width),
+                width: row.width.saturating_sub(indent),
+                ..row
+            };
+            Checkbox::new(label.as_str(), *checked)
+                .checked_symbol(self.checked_symbol.as_ref())
+                .unchecked_symbol(self.unchecked_symbol.as_ref())
+                .checkbox_style(self.checkbox_style)
+                .label_style(self.label_style)
+                .monochrome(self.monochrome)
+                .render(sub_area, buf);
+        }
+    }
+
+    /// Breaks the label into the lines rendered within `max_width`, wrapping or truncating each
+    /// of its lines and reordering them for display.
+    ///
+    /// Wrapped lines are kept in `cache`, when given, and reused while the label and width stay
+    /// the same.
+    fn label_lines<'l>(
+        &self,
+        label: Vec<Line<'l>>,
+        max_width: u16,
+        cache: Option<&'l mut WrapCache>,
+    ) -> Vec<Line<'l>> {
+        let mut label = label;
+        let value = self.split_value(&mut label);
+
+        // Double width labels take two cells per glyph, so lay them out in half the space
+        let max_width = if self.double_width_label {
+            max_width / 2
+        } else {
+            max_width
+        };
+        // The key is laid out in the space left of the value and at least one fill cell
+        let key_width = value.as_ref().map_or(max_width, |(_, value)| {
+            max_width.saturating_sub(value.width() as u16 + 1)
+        });
+        let mut lines = if self.wrap_label {
+            let wrap = |label: &[Line<'_>]| self.display_lines(self.wrap_lines(label, key_width));
+            match cache {
+                Some(cache) => cache.lines(&label, key_width, wrap),
+                None => wrap(&label),
+            }
+        } else {
+            let lines = label
+                .into_iter()
+                .enumerate()
+                .map(|(i, line)| {
+                    if i == 0 {
+                        self.truncate_first_line(line, key_width)
+                    } else {
+                        self.truncate_label(line, key_width)
+                    }
+                })
+                .collect();
+            self.display_lines(lines)
+        };
+
+        if let Some((mut fill, value)) = value {
+            if lines.is_empty() {
+                lines.push(Line::default());
+            }
+            let first = &mut lines[0];
+            let gap = max_width.saturating_sub((first.width() + value.width()) as u16);
+            let fill_width = self.fill_char.width().unwrap_or(1).max(1);
+            let count = (usize::from(gap) / fill_width).max(1);
+            fill.content = self.fill_char.to_string().repeat(count).into();
+            first.spans.extend([fill, value]);
+        }
+        lines.extend(self.description_lines(max_width));
+        lines
+    }
+
+    /// Returns the rows of the description laid out within `max_width` like the label, or none
+    /// without a description or while it is hidden until focused.
+    fn description_lines(&self, max_width: u16) -> Vec<Line<'static>> {
+        let Some(description) = &self.description else {
+            return Vec::new();
+        };
+        if self.description_on_focus && !self.focused {
+            return Vec::new();
+        }
+        let style = self
+            .style
+            .patch(self.description_style)
+            .patch(description.style);
+        let line = Line::from(
+            description
+                .spans
+                .iter()
+                .map(|s| Span::styled(self.sanitize(&s.content).into_owned(), style.patch(s.style)))
+                .collect::<Vec<_>>(),
+        );
+        let mut lines = if self.wrap_label {
+            layout::wrap_line(&line, max_width, self.wrap_mode)
+        } else {
+            vec![self.truncate_label(line, max_width)]
+        };
+        self.remove_colors(&mut lines);
+        self.display_lines(lines)
+    }
+
+    /// Splits the fill and the value of a [`Checkbox::label_value`] off the first line of the
+    /// styled label, returning them in that order.
+    fn split_value<'l>(&self, label: &mut [Line<'l>]) -> Option<(Span<'l>, Span<'l>)> {
+        self.value.as_ref()?;
+        let spans = &mut label.first_mut()?.spans;
+        let value = spans.pop()?;
+        let fill = spans.pop()?;
+        Some((fill, value))
+    }
+
+    /// Wraps each line of the label within `max_width`, styling the lines after the first with
+    /// the continuation style.
+    fn wrap_lines(&self, label: &[Line<'_>], max_width: u16) -> Vec<Line<'static>> {
+        let mut lines: Vec<_> = label
+            .iter()
+            .flat_map(|line| layout::wrap_line(line, max_width, self.wrap_mode))
+            .collect();
+        for line in lines.iter_mut().skip(1) {
+            for span in &mut line.spans {
+                span.style = self.render_style(span.style.patch(self.continuation_style));
+            }
+        }
+        lines
+    }
+
+    /// Reorders and widens the laid out label lines for display.
+    fn display_lines<'l>(&self, mut lines: Vec<Line<'l>>) -> Vec<Line<'l>> {
+        if self.bidi {
+            lines = lines.iter().map(Self::bidi_reorder).collect();
+        }
+        if self.double_width_label {
+            lines = lines.iter().map(Self::double_width).collect();
+        }
+        lines
+    }
+
+    /// Follows every glyph of `line` with a space of the same style.
+    fn double_width(line: &Line<'_>) -> Line<'static> {
+        let spans = line.spans.iter().map(|span| {
+            let content: String = span.content.chars().flat_map(|c| [c, ' ']).collect();
+            Span::styled(content, span.style)
+        });
+        Line::from(spans.collect::<Vec<_>>())
+    }
+
+    /// Reorders a line from logical to visual order using the Unicode bidi algorithm, keeping
+    /// the style of each character.
+    fn bidi_reorder<'l>(line: &Line<'l>) -> Line<'l> {
+        let mut text = String::new();
+        let mut styles = Vec::new();
+        for span in &line.spans {
+            text.push_str(&span.content);
+            styles.extend(std::iter::repeat(span.style).take(span.content.len()));
+        }
+
+        let info = BidiInfo::new(&text, None);
+        let Some(paragraph) = info.paragraphs.first() else {
+            return line.clone();
+        };
+        let (levels, runs) = info.visual_runs(paragraph, paragraph.range.clone());
+
+        let mut spans: Vec<Span<'static>> = Vec::new();
+        for run in runs {
+            let chars = text[run.clone()]
+                .char_indices()
+                .map(|(i, c)| (c, styles[run.start + i]));
+            let chars: Vec<_> = if levels[run.start].is_rtl() {
+                chars.rev().collect()
+            } else {
+                chars.collect()
+            };
+            for (c, style) in chars {
+                match spans.last_mut() {
+                    Some(span) if span.style == style => span.content.to_mut().push(c),
+                    _ => spans.push(Span::styled(c.to_string(), style)),
+                }
+            }
+        }
+        Line::from(spans)
+    }
+
+    /// Shortens a single-line label to `max_width` according to the truncation settings.
+    fn truncate_label<'l>(&self, label: Line<'l>, max_width: u16) -> Line<'l> {
+        match self.truncate_suffix {
+            Some(suffix_chars) if label.width() > usize::from(max_width) => {
+                Self::truncate_middle(&label, max_width, suffix_chars)
+            }
+            _ => label,
+        }
+    }
+
+    /// Truncates the first label line like [`Checkbox::truncate_label`], reserving the width of
+    /// the required marker in front of it so the marker stays visible.
+    fn truncate_first_line<'l>(&self, mut line: Line<'l>, max_width: u16) -> Line<'l> {
+        if self.is_valid() || line.spans.len() < 2 {
+            return self.truncate_label(line, max_width);
+        }
+        // The marker and its space are the first two spans
+        let rest = line.spans.split_off(2);
+        let marker_width = line.width() as u16;
+        let rest = self.truncate_label(Line::from(rest), max_width.saturating_sub(marker_width));
+        line.spans.extend(rest.spans);
+        line
+    }
+
+    /// Replaces the middle of `line` with `…` so it fits in `max_width` columns, keeping up to
+    /// the last `suffix_chars` characters. Characters are counted as grapheme clusters, which are
+    /// never split, and each keeps the style of its span.
+    fn truncate_middle(line: &Line<'_>, max_width: u16, suffix_chars: usize) -> Line<'static> {
+        let max_width = usize::from(max_width);
+        if max_width == 0 {
+            return Line::default();
+        }
+
+        let chars: Vec<(&str, Style)> = line
+            .spans
+            .iter()
+            .flat_map(|span| span.content.graphemes(true).map(move |g| (g, span.style)))
+            .collect();
+        let char_width = |&(g, _): &(&str, Style)| g.width();
+
+        // The ellipsis takes one cell, the suffix gets as much of the rest as it asks for and the
+        // head fills what is left, without splitting a wide glyph
+        let mut remaining = max_width - 1;
+        let mut suffix_len = 0;
+        for c in chars.iter().rev().take(suffix_chars) {
+            if char_width(c) > remaining {
+                break;
+            }
+            remaining -= char_width(c);
+            suffix_len += 1;
+        }
+        let mut head_len = 0;
+        for c in &chars[..chars.len() - suffix_len] {
+            if char_width(c) > remaining {
+                break;
+            }
+            remaining -= char_width(c);
+            head_len += 1;
+        }
+        let (head, tail) = (&chars[..head_len], &chars[chars.len() - suffix_len..]);
+        let ellipsis_style = head
+            .last()
+            .or_else(|| tail.first())
+            .map_or_else(Style::default, |&(_, style)| style);
+
+        let mut spans: Vec<Span<'static>> = Vec::new();
+        let pieces = head
+            .iter()
+            .copied()
+            .chain(std::iter::once(("…", ellipsis_style)))
+            .chain(tail.iter().copied());
+        for (grapheme, style) in pieces {
+            match spans.last_mut() {
+                Some(span) if span.style == style => span.content.to_mut().push_str(grapheme),
+                _ => spans.push(Span::styled(grapheme.to_string(), style)),
+            }
+        }
+        Line::from(spans)
+    }
+}
+
+/// Returns an owned copy of `text`.
+fn owned_cow(text: Cow<'_, str>) -> Cow<'static, str> {
+    Cow::Owned(text.into_owned())
+}
+
+/// Returns a copy of `span` that owns its text.
+fn owned_span(span: Span<'_>) -> Span<'static> {
+    Span::styled(span.content.into_owned(), span.style)
+}
+
+/// Returns a copy of `line` whose spans own their text.
+fn owned_line(line: Line<'_>) -> Line<'static> {
+    Line {
+        spans: line.spans.into_iter().map(owned_span).collect(),
+        style: line.style,
+        alignment: line.alignment,
+    }
+}
+
+/// Returns whether `c` matches the mnemonic `key`, ignoring case.
+fn chars_match(c: char, key: char) -> bool {
+    c == key || c.to_lowercase().eq(key.to_lowercase())
+}
+
+/// Returns the width of the widest line.
+fn max_line_width(lines: &[Line<'_>]) -> u16 {
+    lines.iter().map(|l| l.width() as u16).max().unwrap_or(0)
+}
+
+/// Returns the symbols of each buffer row, without the cells hidden behind wide glyphs and
+/// without trailing whitespace.
+fn buffer_rows(buffer: &Buffer) -> Vec<String> {
+    let area = buffer.area;
+    (area.top()..area.bottom())
+        .map(|y| {
+            let mut row = String::new();
+            let mut hidden = 0;
+            for x in area.left()..area.right() {
+                if hidden > 0 {
+                    hidden -= 1;
+                    continue;
+                }
+                let symbol = buffer[(x, y)].symbol();
+                row.push_str(symbol);
+                hidden = Span::raw(symbol).width().saturating_sub(1);
+            }
+            row.trim_end().to_string()
+        })
+        .collect()
+}
+
+/// Returns the offset that aligns content of `width` within `available` columns.
+fn align_offset(alignment: HorizontalAlignment, available: u16, width: u16) -> u16 {
+    match alignment {
+        HorizontalAlignment::Left => 0,
+        HorizontalAlignment::Center => available.saturating_sub(width) / 2,
+        HorizontalAlignment::Right => available.saturating_sub(width),
+    }
+}
+
+/// Returns the one-row rect at `x` and `y` relative to `area`, clipped to it, or an empty rect
+/// when the position is outside `area`.
+fn row_rect(area: Rect, x: u16, y: u16, width: u16) -> Rect {
+    if x >= area.width || y >= area.height {
+        return Rect::default();
+    }
+    Rect::new(area.x + x, area.y + y, width.min(area.width - x), 1)
+}
+
+/// Resolved position of each part of the checkbox within its area, shared by rendering and hit
+/// testing.
+#[derive(Default)]
+struct Placement<'l> {
+    guide: Rect,
+    prefix: Rect,
+    suffix: Rect,
+    symbol: Rect,
+    separator: Rect,
+    label: Vec<(Rect, Line<'l>)>,
+    hint: Rect,
+    pill: Option<Rect>,
+    used_rows: u16,
+}
+
+impl Placement<'_> {
+    /// Returns the smallest rect covering every placed part, or `None` when nothing was placed.
+    fn bounds(&self) -> Option<Rect> {
+        [
+            self.prefix,
+            self.symbol,
+            self.separator,
+            self.suffix,
+            self.hint,
+        ]
+        .into_iter()
+        .chain(self.pill)
+        .chain(self.label.iter().map(|(label_area, _)| *label_area))
+        .filter(|rect| !rect.is_empty())
+        .reduce(Rect::union)
+    }
+
+    /// Returns the row of the symbol, falling back to the first label row and then to the top
+    /// of `area` when nothing was placed.
+    fn first_row(&self, area: Rect) -> u16 {
+        if self.symbol.is_empty() {
+            self.label.first().map_or(area.y, |(rect, _)| rect.y)
+        } else {
+            self.symbol.y
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::buffer::Cell;
+    use ratatui::style::{Color, Modifier, Stylize};
+
+    use super::*;
+
+    #[test]
+    fn checkbox_new() {
+        let checkbox = Checkbox::new("Test", true);
+        assert_eq!(checkbox.label, Text::from("Test"));
+        assert!(checkbox.checked);
+    }
+
+    #[test]
+    fn checkbox_default() {
+        let checkbox = Checkbox::default();
+        assert_eq!(checkbox.label, Text::from(Line::default()));
+        assert!(!checkbox.checked);
+    }
+
+    #[test]
+    fn checkbox_label() {
+        let checkbox = Checkbox::default().label("New label");
+        assert_eq!(checkbox.label, Text::from("New label"));
+    }
+
+    #[test]
+    fn checkbox_checked() {
+        let checkbox = Checkbox::default().checked(true);
+        assert!(checkbox.checked);
+    }
+
+    #[test]
+    fn checkbox_style() {
+        let style = Style::default().fg(Color::Red);
+        let checkbox = Checkbox::default().style(style);
+        assert_eq!(checkbox.style, style);
+    }
+
+    #[test]
+    fn checkbox_checkbox_style() {
+        let style = Style::default().fg(Color::Green);
+        let checkbox = Checkbox::default().checkbox_style(style);
+        assert_eq!(checkbox.checkbox_style, style);
+    }
+
+    #[test]
+    fn checkbox_label_style() {
+        let style = Style::default().fg(Color::Blue);
+        let checkbox = Checkbox::default().label_style(style);
+        assert_eq!(checkbox.label_style, style);
+    }
+
+    #[test]
+    fn checkbox_checked_symbol() {
+        let checkbox = Checkbox::default().checked_symbol("[X]");
+        assert_eq!(checkbox.checked_symbol, "[X]");
+    }
+
+    #[test]
+    fn checkbox_unchecked_symbol() {
+        let checkbox = Checkbox::default().unchecked_symbol("[ ]");
+        assert_eq!(checkbox.unchecked_symbol, "[ ]");
+    }
+
+    #[test]
+    fn checkbox_styled_trait() {
+        let checkbox = Checkbox::default().red();
+        assert_eq!(checkbox.style, Style::default().fg(Color::Red));
+    }
+
+    #[test]
+    fn checkbox_render_unchecked() {
+        let checkbox = Checkbox::new("Test", false);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 1));
+        checkbox.render(buffer.area, &mut buffer);
+
+        // The buffer should contain the unchecked symbol followed by space and label
+        assert!(buffer
+            .cell(buffer.area.as_position())
+            .unwrap()
+            .symbol()
+            .starts_with('☐'));
+    }
+
+    #[test]
+    fn checkbox_render_checked() {
+        let checkbox = Checkbox::new("Test", true);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 1));
+        checkbox.render(buffer.area, &mut buffer);
+
+        // The buffer should contain the checked symbol followed by space and label
+        assert!(buffer
+            .cell(buffer.area.as_position())
+            .unwrap()
+            .symbol()
+            .starts_with('☑'));
+    }
+
+    #[test]
+    fn checkbox_render_empty_area() {
+        let checkbox = Checkbox::new("Test", true);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 0, 0));
+
+        // Should not panic
+        checkbox.render(buffer.area, &mut buffer);
+    }
+
+    #[test]
+    fn checkbox_render_with_block() {
+        let checkbox = Checkbox::new("Test", true).block(Block::bordered());
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 12, 3));
+
+        // Should not panic
+        checkbox.render(buffer.area, &mut buffer);
+    }
+
+    #[test]
+    fn checkbox_render_with_custom_symbols() {
+        let checkbox = Checkbox::new("Test", true)
+            .checked_symbol("[X]")
+            .unchecked_symbol("[ ]");
+
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 1));
+        checkbox.render(buffer.area, &mut buffer);
+
+        assert!(buffer
+            .cell(buffer.area.as_position())
+            .unwrap()
+            .symbol()
+            .starts_with('['));
+    }
+
+    #[test]
+    fn checkbox_with_styled_label() {
+        let checkbox = Checkbox::new("Test".blue(), true);
+        assert_eq!(checkbox.label.lines[0].spans[0].style.fg, Some(Color::Blue));
+    }
+
+    #[test]
+    fn checkbox_complex_styling() {
+        let checkbox = Checkbox::new("Feature", true)
+            .style(Style::default().fg(Color::White))
+            .checkbox_style(
+                Style::default()
+                    .fg(Color::Green)
+                    .add_modifier(Modifier::BOLD),
+            )
+            .label_style(Style::default().fg(Color::Gray));
+
+        assert_eq!(checkbox.style.fg, Some(Color::White));
+        assert_eq!(checkbox.checkbox_style.fg, Some(Color::Green));
+        assert_eq!(checkbox.label_style.fg, Some(Color::Gray));
+    }
+
+    #[test]
+    fn checkbox_emoji_symbols() {
+        let checkbox = Checkbox::new("Test", true)
+            .checked_symbol("✅ ")
+            .unchecked_symbol("⬜ ");
+
+        assert_eq!(checkbox.checked_symbol, "✅ ");
+        assert_eq!(checkbox.unchecked_symbol, "⬜ ");
+    }
+
+    #[test]
+    fn checkbox_unicode_symbols() {
+        let checkbox = Checkbox::new("Test", false)
+            .checked_symbol("● ")
+            .unchecked_symbol("○ ");
+
+        assert_eq!(checkbox.checked_symbol, "● ");
+        assert_eq!(checkbox.unchecked_symbol, "○ ");
+    }
+
+    #[test]
+    fn checkbox_arrow_symbols() {
+        let checkbox = Checkbox::new("Test", true)
+            .checked_symbol("▶ ")
+            .unchecked_symbol("▷ ");
+
+        assert_eq!(checkbox.checked_symbol, "▶ ");
+        assert_eq!(checkbox.unchecked_symbol, "▷ ");
+    }
+
+    #[test]
+    fn checkbox_parenthesis_symbols() {
+        let checkbox = Checkbox::new("Test", false)
+            .checked_symbol("(X)")
+            .unchecked_symbol("(O)");
+
+        assert_eq!(checkbox.checked_symbol, "(X)");
+        assert_eq!(checkbox.unchecked_symbol, "(O)");
+    }
+
+    #[test]
+    fn checkbox_minus_symbols() {
+        let checkbox = Checkbox::new("Test", false)
+            .checked_symbol("[+]")
+            .unchecked_symbol("[-]");
+
+        assert_eq!(checkbox.checked_symbol, "[+]");
+        assert_eq!(checkbox.unchecked_symbol, "[-]");
+    }
+
+    #[test]
+    fn checkbox_predefined_minus_symbol() {
+        use crate::symbols;
+        let checkbox = Checkbox::new("Test", false).unchecked_symbol(symbols::UNCHECKED_MINUS);
+
+        assert_eq!(checkbox.unchecked_symbol, "[-]");
+    }
+
+    #[test]
+    fn checkbox_predefined_parenthesis_symbols() {
+        use crate::symbols;
+        let checkbox = Checkbox::new("Test", true)
+            .checked_symbol(symbols::CHECKED_PARENTHESIS_X)
+            .unchecked_symbol(symbols::UNCHECKED_PARENTHESIS_O);
+
+        assert_eq!(checkbox.checked_symbol, "(X)");
+        assert_eq!(checkbox.unchecked_symbol, "(O)");
+    }
+
+    #[test]
+    fn checkbox_render_emoji() {
+        let checkbox = Checkbox::new("Emoji", true)
+            .checked_symbol("✅ ")
+            .unchecked_symbol("⬜ ");
+
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 15, 1));
+        checkbox.render(buffer.area, &mut buffer);
+
+        // Should render without panic
+        assert!(buffer.area.area() > 0);
+    }
+
+    #[test]
+    fn checkbox_label_style_overrides() {
+        let checkbox = Checkbox::new("Test", true)
+            .style(Style::default().fg(Color::White))
+            .label_style(Style::default().fg(Color::Blue));
+
+        assert_eq!(checkbox.style.fg, Some(Color::White));
+        assert_eq!(checkbox.label_style.fg, Some(Color::Blue));
+    }
+
+    #[test]
+    fn checkbox_focus_style_frames_cycle() {
+        let frames = [
+            Style::default().fg(Color::Cyan),
+            Style::default().fg(Color::Blue),
+        ];
+        let render = |frame: usize| {
+            let checkbox = Checkbox::new("Test", false)
+                .focused(true)
+                .focus_style_frames(&frames)
+                .frame(frame);
+            let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 1));
+            checkbox.render(buffer.area, &mut buffer);
+            buffer
+        };
+
+        assert_eq!(render(0)[(0, 0)].fg, Color::Cyan);
+        assert_eq!(render(0)[(2, 0)].fg, Color::Cyan);
+        assert_eq!(render(1)[(0, 0)].fg, Color::Blue);
+        assert_eq!(render(1)[(2, 0)].fg, Color::Blue);
+        // Frame indices wrap around the available styles
+        assert_eq!(render(2)[(0, 0)].fg, Color::Cyan);
+    }
+
+    #[test]
+    fn checkbox_focus_style_frames_ignored_when_unfocused() {
+        let checkbox = Checkbox::new("Test", false)
+            .focus_style_frames(&[Style::default().fg(Color::Cyan)])
+            .frame(0);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 1));
+        checkbox.render(buffer.area, &mut buffer);
+
+        assert_eq!(buffer[(0, 0)].fg, Color::Reset);
+    }
+
+    #[test]
+    fn checkbox_left_and_right_wrapped_center_rows_match() {
+        let render = |position: LabelPosition| {
+            let checkbox = Checkbox::new("aa bb cc", true)
+                .label_position(position)
+                .vertical_alignment(VerticalAlignment::Center)
+                .wrap_label(true);
+            let mut buffer = Buffer::empty(Rect::new(0, 0, 6, 5));
+            checkbox.render(buffer.area, &mut buffer);
+            (0..5)
+                .map(|y| (0..6).map(|x| buffer[(x, y)].symbol()).collect::<String>())
+                .collect::<Vec<_>>()
+        };
+
+        let right = render(LabelPosition::Right);
+        let left = render(LabelPosition::Left);
+
+        // Three wrapped lines centered in five rows occupy rows 1..=3 in both positions, with
+        // the checkbox on the first of them
+        for rows in [&right, &left] {
+            assert_eq!(rows[0].trim(), "");
+            assert!(rows[1].contains('☑') && rows[1].contains("aa"));
+            assert!(rows[2].contains("bb"));
+            assert!(rows[3].contains("cc"));
+            assert_eq!(rows[4].trim(), "");
+        }
+        assert!(right[1].starts_with('☑'));
+        assert!(left[1].trim_end().ends_with('☑'));
+    }
+
+    #[test]
+    fn checkbox_truncate_keep_suffix() {
+        let checkbox = Checkbox::new("quarterly_report_final.pdf", false).truncate_keep_suffix(4);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 14, 1));
+        checkbox.render(buffer.area, &mut buffer);
+
+        assert_eq!(buffer, Buffer::with_lines(["☐ quarter….pdf"]));
+    }
+
+    #[test]
+    fn checkbox_truncate_keep_suffix_fits() {
+        let checkbox = Checkbox::new("short.pdf", false).truncate_keep_suffix(4);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 14, 1));
+        checkbox.render(buffer.area, &mut buffer);
+
+        assert_eq!(buffer, Buffer::with_lines(["☐ short.pdf   "]));
+    }
+
+    #[test]
+    fn checkbox_pill() {
+        let checkbox = Checkbox::new("Test", true)
+            .pill(true)
+            .pill_style(Style::default().bg(Color::Blue));
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 1));
+        checkbox.render(buffer.area, &mut buffer);
+
+        let mut expected = Buffer::with_lines(["▐☑ Test▌  "]);
+        expected.set_style(Rect::new(1, 0, 6, 1), Style::default().bg(Color::Blue));
+        expected.set_style(Rect::new(0, 0, 1, 1), Style::default().fg(Color::Blue));
+        expected.set_style(Rect::new(7, 0, 1, 1), Style::default().fg(Color::Blue));
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn checkbox_sub_options() {
+        let checkbox = Checkbox::new("Parent", true).sub_options(vec![
+            ("Child A".to_string(), true),
+            ("Child B".to_string(), false),
+        ]);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 12, 4));
+        checkbox.render(buffer.area, &mut buffer);
+
+        assert_eq!(
+            buffer,
+            Buffer::with_lines([
+                "☑ Parent    ",
+                "  ☑ Child A ",
+                "  ☐ Child B ",
+                "            ",
+            ])
+        );
+    }
+
+    #[test]
+    fn checkbox_style_scope() {
+        let render = |scope: StyleScope| {
+            let checkbox = Checkbox::new("Test", true)
+                .block(Block::bordered())
+                .style(Style::default().bg(Color::Blue))
+                .style_scope(scope);
+            let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 3));
+            checkbox.render(buffer.area, &mut buffer);
+            buffer
+        };
+
+        // (border, empty inner cell, symbol)
+        let bg = |buffer: &Buffer| (buffer[(0, 0)].bg, buffer[(8, 1)].bg, buffer[(1, 1)].bg);
+        assert_eq!(
+            bg(&render(StyleScope::All)),
+            (Color::Blue, Color::Blue, Color::Blue)
+        );
+        assert_eq!(
+            bg(&render(StyleScope::Inner)),
+            (Color::Reset, Color::Blue, Color::Blue)
+        );
+        assert_eq!(
+            bg(&render(StyleScope::Content)),
+            (Color::Reset, Color::Reset, Color::Blue)
+        );
+    }
+
+    #[test]
+    fn checkbox_partial_fill() {
+        let symbol = |ratio: f32| {
+            Checkbox::new("Test", false)
+                .partial(ratio)
+                .current_symbol()
+                .to_string()
+        };
+
+        assert_eq!(symbol(0.0), "☐");
+        assert_eq!(symbol(0.125), "▁");
+        assert_eq!(symbol(0.5), "▄");
+        assert_eq!(symbol(0.875), "▇");
+        assert_eq!(symbol(1.0), "☑");
+        // Out of range ratios are clamped
+        assert_eq!(symbol(-1.0), "☐");
+        assert_eq!(symbol(2.0), "☑");
+    }
+
+    #[test]
+    fn checkbox_render_partial_fill() {
+        let checkbox = Checkbox::new("Test", false).partial(0.5);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 6, 1));
+        checkbox.render(buffer.area, &mut buffer);
+
+        assert_eq!(buffer, Buffer::with_lines(["▄ Test"]));
+    }
+
+    #[test]
+    fn checkbox_color_scheme_presets() {
+        let colors = |checkbox: Checkbox| {
+            let mut buffer = Buffer::empty(Rect::new(0, 0, 6, 1));
+            checkbox.render(buffer.area, &mut buffer);
+            (buffer[(0, 0)].fg, buffer[(2, 0)].fg, buffer[(2, 0)].bg)
         };
-        self.render_checkbox(inner, buf);
+
+        let dark = ColorScheme::dark();
+        assert_eq!(
+            colors(Checkbox::new("Test", true).color_scheme(dark)),
+            (Color::Green, Color::White, Color::Reset)
+        );
+        assert_eq!(
+            colors(Checkbox::new("Test", false).color_scheme(dark)),
+            (Color::Gray, Color::White, Color::Reset)
+        );
+        assert_eq!(
+            colors(
+                Checkbox::new("Test", false)
+                    .color_scheme(dark)
+                    .focused(true)
+            ),
+            (Color::Gray, Color::White, Color::DarkGray)
+        );
+
+        let light = ColorScheme::light();
+        assert_eq!(
+            colors(Checkbox::new("Test", true).color_scheme(light)),
+            (Color::Green, Color::Black, Color::Reset)
+        );
+        assert_eq!(
+            colors(Checkbox::new("Test", false).color_scheme(light)),
+            (Color::DarkGray, Color::Black, Color::Reset)
+        );
+        assert_eq!(
+            colors(
+                Checkbox::new("Test", true)
+                    .color_scheme(light)
+                    .focused(true)
+            ),
+            (Color::Green, Color::Black, Color::Gray)
+        );
+    }
+
+    #[test]
+    fn checkbox_color_scheme_overridden_by_styles() {
+        let checkbox = Checkbox::new("Test", true)
+            .color_scheme(ColorScheme::dark())
+            .checkbox_style(Style::default().fg(Color::Yellow));
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 6, 1));
+        checkbox.render(buffer.area, &mut buffer);
+
+        assert_eq!(buffer[(0, 0)].fg, Color::Yellow);
+    }
+
+    #[test]
+    fn checkbox_bidi_reorders_mixed_label() {
+        let label = "abc \u{05D0}\u{05D1}\u{05D2}";
+        let checkbox = Checkbox::new(label, false).bidi(true);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 9, 1));
+        checkbox.render(buffer.area, &mut buffer);
+
+        let info = BidiInfo::new(label, None);
+        let paragraph = &info.paragraphs[0];
+        let visual = info.reorder_line(paragraph, paragraph.range.clone());
+        assert_eq!(visual, "abc \u{05D2}\u{05D1}\u{05D0}");
+        assert_eq!(buffer, Buffer::with_lines([format!("☐ {visual}")]));
+    }
+
+    #[test]
+    fn checkbox_truncate_with_ellipsis() {
+        let checkbox = Checkbox::new("Enable all features", false).truncate(true);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 13, 1));
+        checkbox.render(buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(["☐ Enable all…"]));
+
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 16, 1));
+        Checkbox::new("日本語のラベル", false)
+            .max_width(10)
+            .truncate(true)
+            .render(buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(["☐ 日本語…       "]));
+    }
+
+    #[test]
+    fn checkbox_truncate_disabled_clips() {
+        let checkbox = Checkbox::new("Enable all features", false)
+            .truncate_keep_suffix(3)
+            .truncate(false);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 13, 1));
+        checkbox.render(buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(["☐ Enable all "]));
+    }
+
+    #[test]
+    fn checkbox_render_and_measure() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 12, 3));
+        let painted = Checkbox::new("Test", true)
+            .horizontal_alignment(HorizontalAlignment::Center)
+            .vertical_alignment(VerticalAlignment::Center)
+            .render_and_measure(buffer.area, &mut buffer);
+        assert_eq!(painted, Rect::new(3, 1, 6, 1));
+        assert_eq!(
+            buffer,
+            Buffer::with_lines(["            ", "   ☑ Test   ", "            "])
+        );
+
+        let mut buffer = Buffer::empty(Rect::new(2, 1, 12, 3));
+        let painted = Checkbox::new("Test", true)
+            .horizontal_alignment(HorizontalAlignment::Right)
+            .label_position(LabelPosition::Bottom)
+            .render_and_measure(buffer.area, &mut buffer);
+        assert_eq!(painted, Rect::new(10, 1, 4, 2));
+        assert_eq!(buffer[(13, 1)].symbol(), "☑");
+        assert_eq!(buffer[(10, 2)].symbol(), "T");
+    }
+
+    #[test]
+    fn checkbox_render_and_measure_sub_options() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 20, 3));
+        let painted = Checkbox::new("All", true)
+            .sub_options(vec![("Subtask".to_string(), false)])
+            .render_and_measure(buffer.area, &mut buffer);
+        assert_eq!(painted, Rect::new(0, 0, 11, 2));
+
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 20, 3));
+        let painted = Checkbox::new("All", true)
+            .block(Block::bordered())
+            .render_and_measure(buffer.area, &mut buffer);
+        assert_eq!(painted, buffer.area);
+    }
+
+    #[test]
+    fn checkbox_continuation_style() {
+        let dim = Style::new().fg(Color::DarkGray);
+        let checkbox = Checkbox::new("aaa bbb ccc", false)
+            .wrap_label(true)
+            .continuation_style(dim);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 5, 3));
+        checkbox.render(buffer.area, &mut buffer);
+
+        let mut expected = Buffer::with_lines(["☐ aaa", "  bbb", "  ccc"]);
+        expected.set_style(Rect::new(2, 1, 3, 2), dim);
+        assert_eq!(buffer, expected);
+
+        // A label that fits on one line is left alone
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 13, 1));
+        Checkbox::new("aaa bbb ccc", false)
+            .wrap_label(true)
+            .continuation_style(dim)
+            .render(buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(["☐ aaa bbb ccc"]));
+    }
+
+    #[test]
+    fn checkbox_symbol_alignment() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 12, 1));
+        Checkbox::new("Test", true)
+            .symbol_alignment(HorizontalAlignment::Left)
+            .horizontal_alignment(HorizontalAlignment::Center)
+            .render(buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(["☑    Test   "]));
+
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 12, 1));
+        Checkbox::new("Test", true)
+            .label_position(LabelPosition::Left)
+            .symbol_alignment(HorizontalAlignment::Right)
+            .render(buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(["Test       ☑"]));
+    }
+
+    #[test]
+    fn checkbox_symbol_alignment_vertical() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 6, 2));
+        Checkbox::new("Test", true)
+            .label_position(LabelPosition::Bottom)
+            .symbol_alignment(HorizontalAlignment::Right)
+            .render(buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(["     ☑", "Test  "]));
+    }
+
+    #[test]
+    fn checkbox_overflow_keeps_symbol_visible() {
+        for alignment in [
+            HorizontalAlignment::Left,
+            HorizontalAlignment::Center,
+            HorizontalAlignment::Right,
+        ] {
+            let mut buffer = Buffer::empty(Rect::new(0, 0, 3, 1));
+            Checkbox::new("Abcdefghij", true)
+                .horizontal_alignment(alignment)
+                .render(buffer.area, &mut buffer);
+            assert_eq!(buffer, Buffer::with_lines(["☑ A"]), "{alignment:?}");
+        }
+    }
+
+    #[test]
+    fn checkbox_overflow_wide_symbol() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 3, 1));
+        Checkbox::new("Abcdefghij", true)
+            .checked_symbol("[x]")
+            .render(buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(["[x]"]));
+
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 2, 1));
+        Checkbox::new("Abcdefghij", true)
+            .checked_symbol("[x]")
+            .render(buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(["[x"]));
+    }
+
+    #[test]
+    fn checkbox_full_width_hit_paints_min_width_row() {
+        let checkbox = Checkbox::new("Test", true)
+            .style(Style::default().bg(Color::Blue))
+            .style_scope(StyleScope::Content)
+            .min_width(10)
+            .full_width_hit(true);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 14, 2));
+        checkbox.clone().render(buffer.area, &mut buffer);
+
+        let mut expected = Buffer::with_lines(["☑ Test        ", "              "]);
+        expected.set_style(Rect::new(0, 0, 14, 1), Style::default().bg(Color::Blue));
+        assert_eq!(buffer, expected);
+
+        let area = Rect::new(0, 0, 14, 2);
+        assert!(checkbox.hit_test(area, 13, 0));
+        assert!(!checkbox.hit_test(area, 0, 1));
+        assert!(!checkbox.clone().full_width_hit(false).hit_test(area, 9, 0));
+    }
+
+    #[test]
+    fn checkbox_full_width_hit_respects_max_width() {
+        let checkbox = Checkbox::new("Test", true)
+            .style(Style::default().bg(Color::Blue))
+            .style_scope(StyleScope::Content)
+            .max_width(10)
+            .full_width_hit(true);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 14, 1));
+        checkbox.clone().render(buffer.area, &mut buffer);
+
+        let mut expected = Buffer::with_lines(["☑ Test        "]);
+        expected.set_style(Rect::new(0, 0, 10, 1), Style::default().bg(Color::Blue));
+        assert_eq!(buffer, expected);
+        assert!(checkbox.hit_test(buffer.area, 9, 0));
+        assert!(!checkbox.hit_test(buffer.area, 10, 0));
+    }
+
+    #[test]
+    fn checkbox_wrap_mode_char() {
+        let label = "0123456789".repeat(4);
+        let checkbox = Checkbox::new(label.as_str(), false)
+            .wrap_label(true)
+            .wrap_mode(WrapMode::Char);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 12, 5));
+        checkbox.clone().render(buffer.area, &mut buffer);
+
+        assert_eq!(
+            buffer,
+            Buffer::with_lines([
+                "☐ 0123456789",
+                "  0123456789",
+                "  0123456789",
+                "  0123456789",
+                "            ",
+            ])
+        );
+        assert_eq!(checkbox.measure(12), (12, 4));
+    }
+
+    #[test]
+    fn checkbox_to_plain_string() {
+        assert_eq!(Checkbox::new("Test", true).to_plain_string(), "☑ Test");
+        assert_eq!(Checkbox::new("Test", false).to_plain_string(), "☐ Test");
+        assert_eq!(
+            Checkbox::new("Test", true)
+                .checked_symbol("[x]")
+                .separator(" - ")
+                .to_plain_string(),
+            "[x] - Test"
+        );
+        assert_eq!(
+            Checkbox::new("Test", false)
+                .unchecked_symbol("[ ]")
+                .label_position(LabelPosition::Left)
+                .to_plain_string(),
+            "Test [ ]"
+        );
+        assert_eq!(
+            Checkbox::default()
+                .label_text(Text::from(vec![Line::from("Title"), Line::from("detail")]))
+                .to_plain_string(),
+            "☐ Title detail"
+        );
+        assert_eq!(Checkbox::new("", true).to_plain_string(), "☑");
+    }
+
+    #[test]
+    fn checkbox_to_lines() {
+        let checkbox = Checkbox::new("Test", true).label_position(LabelPosition::Bottom);
+        assert_eq!(checkbox.to_lines(6), ["☑", "Test"]);
+
+        let checkbox = Checkbox::new("Test", false)
+            .checked_symbol("✅")
+            .horizontal_alignment(HorizontalAlignment::Right);
+        assert_eq!(checkbox.to_lines(8), ["  ☐ Test"]);
+        assert_eq!(checkbox.checked(true).to_lines(8), [" ✅ Test"]);
+    }
+
+    #[test]
+    fn checkbox_borrowed_and_owned_labels_render_alike() {
+        let owned = Checkbox::new(
+            Line::from(vec!["Save ".to_string().red(), "all".into()]),
+            true,
+        )
+        .label_style(Style::default().add_modifier(Modifier::BOLD));
+        let borrowed = Checkbox::new(Line::from(vec!["Save ".red(), "all".into()]), true)
+            .label_style(Style::default().add_modifier(Modifier::BOLD));
+
+        let mut owned_buffer = Buffer::empty(Rect::new(0, 0, 12, 1));
+        owned.render(owned_buffer.area, &mut owned_buffer);
+        let mut borrowed_buffer = Buffer::empty(Rect::new(0, 0, 12, 1));
+        borrowed.render(borrowed_buffer.area, &mut borrowed_buffer);
+
+        let mut expected = Buffer::with_lines(["☑ Save all  "]);
+        expected.set_style(Rect::new(2, 0, 8, 1), Modifier::BOLD);
+        expected.set_style(Rect::new(2, 0, 5, 1), Style::default().fg(Color::Red));
+        assert_eq!(owned_buffer, expected);
+        assert_eq!(borrowed_buffer, expected);
+    }
+
+    #[test]
+    fn checkbox_symbol_anchor() {
+        let checkbox = Checkbox::new("Label", true)
+            .label_position(LabelPosition::Top)
+            .horizontal_alignment(HorizontalAlignment::Center)
+            .symbol_alignment(HorizontalAlignment::Left);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 9, 2));
+        checkbox.clone().render(buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(["  Label  ", "☑        "]));
+
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 9, 2));
+        checkbox
+            .symbol_anchor(SymbolAnchor::LabelEdge)
+            .render(buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(["  Label  ", "  ☑      "]));
+    }
+
+    #[test]
+    fn checkbox_symbol_anchor_follows_alignment() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 9, 2));
+        Checkbox::new("Label", true)
+            .label_position(LabelPosition::Bottom)
+            .horizontal_alignment(HorizontalAlignment::Right)
+            .symbol_alignment(HorizontalAlignment::Left)
+            .symbol_anchor(SymbolAnchor::LabelEdge)
+            .render(buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(["    ☑    ", "    Label"]));
+    }
+
+    #[test]
+    fn checkbox_from_str() {
+        let checkbox = Checkbox::from("Label");
+        assert_eq!(checkbox.label, Text::from("Label"));
+        assert!(!checkbox.checked);
+    }
+
+    #[test]
+    fn checkbox_from_string() {
+        let checkbox = Checkbox::from(String::from("Label"));
+        assert_eq!(checkbox.label, Text::from("Label"));
+        assert!(!checkbox.checked);
+    }
+
+    #[test]
+    fn checkbox_from_cow() {
+        let checkbox: Checkbox = Cow::Borrowed("Label").into();
+        assert_eq!(checkbox, Checkbox::new("Label", false));
+        let checkbox: Checkbox = Cow::<str>::Owned(String::from("Label")).into();
+        assert_eq!(checkbox, Checkbox::new("Label", false));
+    }
+
+    #[test]
+    fn checkbox_strikethrough_when_checked() {
+        let checkbox = Checkbox::new("Todo", true)
+            .label_style(Style::default().fg(Color::Gray))
+            .strikethrough_when_checked(true);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 6, 1));
+        checkbox.clone().render(buffer.area, &mut buffer);
+
+        let mut expected = Buffer::with_lines(["☑ Todo"]);
+        expected.set_style(
+            Rect::new(2, 0, 4, 1),
+            Style::default()
+                .fg(Color::Gray)
+                .add_modifier(Modifier::CROSSED_OUT),
+        );
+        assert_eq!(buffer, expected);
+
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 6, 1));
+        checkbox.checked(false).render(buffer.area, &mut buffer);
+        let mut expected = Buffer::with_lines(["☐ Todo"]);
+        expected.set_style(Rect::new(2, 0, 4, 1), Style::default().fg(Color::Gray));
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn checkbox_prefix_and_suffix() {
+        let checkbox = Checkbox::new("Inbox", false).prefix("• ").suffix(" (3)");
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 14, 1));
+        checkbox.clone().render(buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(["• ☐ Inbox (3) "]));
+        assert_eq!(checkbox.measure(20), (13, 1));
+
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 14, 1));
+        checkbox
+            .label_position(LabelPosition::Left)
+            .render(buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(["• Inbox ☐ (3) "]));
+    }
+
+    #[test]
+    fn checkbox_prefix_follows_alignment() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 12, 2));
+        Checkbox::new("Test", true)
+            .prefix("> ")
+            .horizontal_alignment(HorizontalAlignment::Right)
+            .render(buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(["    > ☑ Test", "            "]));
+
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 6, 2));
+        Checkbox::new("Test", true)
+            .prefix("> ")
+            .label_position(LabelPosition::Bottom)
+            .render(buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(["> ☑   ", "  Test"]));
+    }
+
+    #[test]
+    fn checkbox_prefix_skipped_when_too_narrow() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 2, 1));
+        Checkbox::new("Test", true)
+            .prefix("• ")
+            .render(buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(["☑ "]));
+    }
+
+    #[test]
+    fn checkbox_width_range() {
+        let checkbox = Checkbox::new("Test", true).width_range(10, 20);
+        assert_eq!(checkbox.measure(40), (10, 1));
+        assert_eq!(
+            checkbox,
+            Checkbox::new("Test", true).min_width(10).max_width(20)
+        );
+    }
+
+    #[test]
+    fn checkbox_width_range_inverted() {
+        let checkbox = Checkbox::new("A label longer than the range", true).width_range(40, 20);
+        assert_eq!(checkbox.min_width, Some(20));
+        assert_eq!(checkbox.max_width, Some(20));
+        assert_eq!(checkbox.measure(60), (20, 1));
+        assert_eq!(checkbox.constrained_area(Rect::new(0, 0, 60, 1)).width, 20);
+
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 30, 1));
+        checkbox.render(buffer.area, &mut buffer);
+        assert_eq!(
+            buffer,
+            Buffer::with_lines(["☑ A label longer tha          "])
+        );
+    }
+
+    #[test]
+    fn checkbox_mnemonic() {
+        let checkbox = Checkbox::new("Save on exit", false)
+            .label_style(Style::default().fg(Color::Gray))
+            .mnemonic('x');
+        assert_eq!(checkbox.mnemonic_key(), Some('x'));
+
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 14, 1));
+        checkbox.render(buffer.area, &mut buffer);
+        let mut expected = Buffer::with_lines(["☐ Save on exit"]);
+        expected.set_style(Rect::new(2, 0, 12, 1), Style::default().fg(Color::Gray));
+        expected.set_style(Rect::new(11, 0, 1, 1), Modifier::UNDERLINED);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn checkbox_mnemonic_ignores_case() {
+        let checkbox = Checkbox::new("Save on exit", false).mnemonic('s');
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 14, 1));
+        checkbox.render(buffer.area, &mut buffer);
+
+        let mut expected = Buffer::with_lines(["☐ Save on exit"]);
+        expected.set_style(Rect::new(2, 0, 1, 1), Modifier::UNDERLINED);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn checkbox_mnemonic_missing() {
+        let checkbox = Checkbox::new("Save", false).mnemonic('q');
+        assert_eq!(checkbox.mnemonic_key(), None);
+
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 6, 1));
+        checkbox.render(buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(["☐ Save"]));
+    }
+
+    #[test]
+    fn checkbox_scroll_zero() {
+        let checkbox = Checkbox::new("Alpha beta gamma", false)
+            .wrap_label(true)
+            .scroll(0);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 8, 3));
+        checkbox.render(buffer.area, &mut buffer);
+        assert_eq!(
+            buffer,
+            Buffer::with_lines(["☐ Alpha ", "  beta  ", "  gamma "])
+        );
+    }
+
+    #[test]
+    fn checkbox_scroll_hides_leading_lines() {
+        let checkbox = Checkbox::new("Alpha beta gamma", false)
+            .wrap_label(true)
+            .scroll(1);
+        assert_eq!(checkbox.measure(8), (7, 2));
+
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 8, 3));
+        checkbox.render(buffer.area, &mut buffer);
+        assert_eq!(
+            buffer,
+            Buffer::with_lines(["  beta  ", "  gamma ", "        "])
+        );
+    }
+
+    #[test]
+    fn checkbox_scroll_vertical() {
+        let checkbox = Checkbox::new("Alpha beta", false)
+            .label_position(LabelPosition::Bottom)
+            .wrap_label(true)
+            .scroll(1);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 6, 2));
+        checkbox.render(buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(["Alpha ", "beta  "]));
+    }
+
+    #[test]
+    fn checkbox_scroll_past_end() {
+        let checkbox = Checkbox::new("Alpha beta gamma", false)
+            .wrap_label(true)
+            .scroll(10);
+        assert_eq!(checkbox.measure(8).1, 0);
+
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 8, 3));
+        checkbox.render(buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::empty(buffer.area));
+    }
+
+    #[test]
+    fn checkbox_ascii_constructor() {
+        let checkbox = Checkbox::ascii("Test", true);
+        assert!(checkbox.checked);
+        assert_eq!(checkbox.checked_symbol, symbols::CHECKED_X);
+        assert_eq!(checkbox.unchecked_symbol, symbols::UNCHECKED_SPACE);
+        assert_eq!(checkbox.indeterminate_symbol, symbols::UNCHECKED_MINUS);
+    }
+
+    #[test]
+    fn checkbox_unicode_constructor() {
+        let checkbox = Checkbox::unicode("Test", false);
+        assert!(!checkbox.checked);
+        assert_eq!(checkbox, Checkbox::new("Test", false));
+    }
+
+    #[test]
+    fn checkbox_emoji_constructor() {
+        let checkbox = Checkbox::emoji("Test", true);
+        assert_eq!(checkbox.checked_symbol, symbols::CHECKED_EMOJI);
+        assert_eq!(checkbox.unchecked_symbol, symbols::UNCHECKED_EMOJI);
+        assert_eq!(checkbox.indeterminate_symbol, symbols::INDETERMINATE);
+
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 8, 1));
+        checkbox.render(buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(["✅ Test "]));
+    }
+
+    #[test]
+    fn checkbox_label_left_keeps_symbol_in_tight_area() {
+        let checkbox = Checkbox::new("Long label", false).label_position(LabelPosition::Left);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 1));
+        checkbox.clone().render(buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(["Long lab ☐"]));
+
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 4, 1));
+        checkbox.render(buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(["Lo ☐"]));
+    }
+
+    #[test]
+    fn checkbox_tree_guides_depth_zero() {
+        let checkbox = Checkbox::new("Root", false).tree_guides(true);
+        assert_eq!(checkbox.measure(20), (6, 1));
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 6, 1));
+        checkbox.render(buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(["☐ Root"]));
+    }
+
+    #[test]
+    fn checkbox_tree_guides_depth_one() {
+        let checkbox = Checkbox::new("Child", true)
+            .indent(1)
+            .tree_guides(true)
+            .guide_style(Style::default().fg(Color::DarkGray));
+        assert_eq!(checkbox.measure(20), (10, 1));
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 1));
+        checkbox.render(buffer.area, &mut buffer);
+        let mut expected = Buffer::with_lines(["├─ ☑ Child"]);
+        expected.set_style(Rect::new(0, 0, 3, 1), Style::default().fg(Color::DarkGray));
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn checkbox_tree_guides_depth_two() {
+        let checkbox = Checkbox::new("Leaf", false).indent(2).tree_guides(true);
+        assert_eq!(checkbox.measure(20), (12, 1));
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 12, 1));
+        checkbox.render(buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(["│  ├─ ☐ Leaf"]));
+    }
+
+    #[test]
+    fn checkbox_indent_without_guides() {
+        let checkbox = Checkbox::new("Leaf", false).indent(2);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 12, 1));
+        checkbox.render(buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(["      ☐ Leaf"]));
+    }
+
+    #[test]
+    fn checkbox_accessible_description_states() {
+        let checkbox = Checkbox::new("Enable feature", true);
+        assert_eq!(
+            checkbox.accessible_description(),
+            "checkbox, checked, Enable feature"
+        );
+
+        let checkbox = checkbox.checked(false);
+        assert_eq!(
+            checkbox.accessible_description(),
+            "checkbox, unchecked, Enable feature"
+        );
+
+        let checkbox = checkbox.indeterminate(true);
+        assert_eq!(
+            checkbox.accessible_description(),
+            "checkbox, indeterminate, Enable feature"
+        );
+    }
+
+    #[test]
+    fn checkbox_accessible_label_replaces_label() {
+        let checkbox = Checkbox::new("[!] Alerts".red(), false)
+            .accessible_label("Alerts")
+            .disabled(true);
+        assert_eq!(
+            checkbox.accessible_description(),
+            "checkbox, unchecked, Alerts, disabled"
+        );
+
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 12, 1));
+        checkbox.render(buffer.area, &mut buffer);
+        assert_eq!(buffer_rows(&buffer), ["☐ [!] Alerts"]);
+    }
+
+    #[test]
+    fn checkbox_gap_takes_label_background() {
+        let checkbox = Checkbox::new("Test", false)
+            .label_style(Style::default().fg(Color::White).bg(Color::Blue));
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 6, 1));
+        checkbox.render(buffer.area, &mut buffer);
+
+        let mut expected = Buffer::with_lines(["☐ Test"]);
+        expected.set_style(Rect::new(1, 0, 1, 1), Style::default().bg(Color::Blue));
+        expected.set_style(
+            Rect::new(2, 0, 4, 1),
+            Style::default().fg(Color::White).bg(Color::Blue),
+        );
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn checkbox_pending_overrides_state() {
+        for checked in [true, false] {
+            let checkbox = Checkbox::new("Sync", checked).pending(true);
+            let mut buffer = Buffer::empty(Rect::new(0, 0, 6, 1));
+            checkbox.render(buffer.area, &mut buffer);
+            assert_eq!(buffer, Buffer::with_lines(["… Sync"]));
+        }
+
+        let checkbox = Checkbox::new("Sync", false)
+            .indeterminate(true)
+            .pending(true)
+            .pending_symbol("[~]");
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 8, 1));
+        checkbox.render(buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(["[~] Sync"]));
+    }
+
+    #[test]
+    fn checkbox_fade_overflow_dims_clipped_label() {
+        let checkbox = Checkbox::new("Enable all features", false)
+            .max_width(10)
+            .fade_overflow(true);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 1));
+        checkbox.render(buffer.area, &mut buffer);
+
+        let mut expected = Buffer::with_lines(["☐ Enable a"]);
+        expected.set_style(Rect::new(7, 0, 3, 1), Modifier::DIM);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn checkbox_fade_overflow_skips_fitting_label() {
+        let checkbox = Checkbox::new("Enable", false).fade_overflow(true);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 1));
+        checkbox.clone().render(buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(["☐ Enable  "]));
+
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 6, 2));
+        checkbox
+            .wrap_label(true)
+            .wrap_mode(WrapMode::WordThenChar)
+            .render(buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(["☐ Enab", "  le  "]));
+    }
+
+    #[test]
+    fn checkbox_default_wrap_keeps_words_whole() {
+        let checkbox = Checkbox::new("Go supercalifragilistic", false).wrap_label(true);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 3));
+        checkbox.render(buffer.area, &mut buffer);
+        assert_eq!(buffer_rows(&buffer), ["☐ Go", "  supercal", ""]);
+    }
+
+    #[test]
+    fn checkbox_symbol_width_aligns_labels() {
+        let checkboxes = [
+            Checkbox::new("Done", true)
+                .checked_symbol("✅")
+                .symbol_width(2),
+            Checkbox::new("Todo", false).symbol_width(2),
+        ];
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 8, 2));
+        for (row, checkbox) in buffer.area.rows().zip(&checkboxes) {
+            checkbox.render(row, &mut buffer);
+        }
+        assert_eq!(buffer, Buffer::with_lines(["✅ Done ", "☐  Todo "]));
+        assert_eq!(checkboxes[1].measure(20), (7, 1));
+    }
+
+    #[test]
+    fn checkbox_symbol_width_follows_symbol_alignment() {
+        let checkbox = Checkbox::new("Todo", false)
+            .label_position(LabelPosition::Left)
+            .symbol_width(3)
+            .symbol_alignment(HorizontalAlignment::Right);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 9, 1));
+        checkbox.render(buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(["Todo    ☐"]));
+    }
+
+    #[test]
+    fn checkbox_symbol_width_narrower_than_symbol() {
+        let checkbox = Checkbox::new("Test", false)
+            .unchecked_symbol("[ ]")
+            .symbol_width(1);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 8, 1));
+        checkbox.render(buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(["[ ] Test"]));
+    }
+
+    #[test]
+    fn checkbox_toggle_in_place() {
+        let mut checkbox = Checkbox::new("Test", false);
+        checkbox.toggle();
+        assert!(checkbox.is_checked());
+        checkbox.toggle();
+        assert!(!checkbox.is_checked());
+    }
+
+    #[test]
+    fn checkbox_set_checked_in_place() {
+        let mut checkbox = Checkbox::new("Test", false);
+        checkbox.set_checked(true);
+        assert!(checkbox.is_checked());
+
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 6, 1));
+        checkbox.render(buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(["☑ Test"]));
+    }
+
+    #[test]
+    fn checkbox_label_value_flush_right() {
+        let checkbox = Checkbox::default()
+            .checked(true)
+            .label_value("Theme", "Dark")
+            .fill_char('.');
+        assert_eq!(checkbox.measure(16), (16, 1));
+        assert_eq!(checkbox.to_plain_string(), "☑ Theme.Dark");
+
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 16, 1));
+        checkbox.render(buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(["☑ Theme.....Dark"]));
     }
-}
 
-impl Checkbox<'_> {
-    fn render_checkbox(&self, area: Rect, buf: &mut Buffer) {
-        if area.is_empty() {
-            return;
-        }
+    #[test]
+    fn checkbox_label_value_default_fill() {
+        let checkbox = Checkbox::default()
+            .label_value("Theme", "Dark".bold())
+            .label_position(LabelPosition::Left);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 14, 1));
+        checkbox.render(buffer.area, &mut buffer);
 
-        // Determine which symbol to use based on checked state
-        let symbol = if self.checked {
-            &self.checked_symbol
-        } else {
-            &self.unchecked_symbol
-        };
+        let mut expected = Buffer::with_lines(["Theme   Dark ☐"]);
+        expected.set_style(Rect::new(8, 0, 4, 1), Modifier::BOLD);
+        assert_eq!(buffer, expected);
+    }
 
-        // Calculate the combined styles
-        let checkbox_style = self.style.patch(self.checkbox_style);
-        let label_style = self.style.patch(self.label_style);
+    #[test]
+    fn checkbox_label_value_mnemonic_in_key() {
+        let checkbox = Checkbox::default()
+            .label_value("Theme", "Dark")
+            .mnemonic('k');
+        assert_eq!(checkbox.to_plain_string(), "☐ Theme Dark");
+    }
 
-        // Apply width constraints
-        let mut render_area = area;
-        if let Some(min_width) = self.min_width {
-            render_area.width = render_area.width.max(min_width);
+    #[test]
+    fn checkbox_label_value_truncates_key() {
+        let checkbox = Checkbox::default()
+            .label_value("Appearance", "Dark")
+            .truncate(true)
+            .fill_char('.');
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 12, 1));
+        checkbox.render(buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(["☐ Appe….Dark"]));
+    }
+
+    #[test]
+    fn checkbox_label_tabs_expanded() {
+        let checkbox = Checkbox::new("a\tb", false);
+        assert_eq!(checkbox.measure(20), (8, 1));
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 8, 1));
+        checkbox.clone().render(buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(["☐ a    b"]));
+
+        assert_eq!(checkbox.tab_width(0).to_plain_string(), "☐ ab");
+    }
+
+    #[test]
+    fn checkbox_label_control_chars_dropped() {
+        let checkbox = Checkbox::new("a\u{7}b\rc", false);
+        assert_eq!(checkbox.measure(20), (5, 1));
+        assert_eq!(checkbox.to_plain_string(), "☐ abc");
+    }
+
+    #[test]
+    fn checkbox_label_unusual_characters_stay_in_bounds() {
+        let labels = [
+            "Tab\tseparated\tlabel",
+            "Cafe\u{301} au lait",
+            "Family \u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467} plan",
+        ];
+        for label in labels {
+            for width in 1..16 {
+                for checkbox in [
+                    Checkbox::new(label, true).wrap_label(true),
+                    Checkbox::new(label, true).truncate(true),
+                ] {
+                    let (measured, height) = checkbox.measure(width);
+                    assert!(measured <= width, "{label:?} at {width}");
+                    let mut buffer = Buffer::empty(Rect::new(0, 0, width, height));
+                    checkbox.render(buffer.area, &mut buffer);
+                }
+            }
         }
-        if let Some(max_width) = self.max_width {
-            render_area.width = render_area.width.min(max_width);
+    }
+
+    #[test]
+    fn checkbox_truncate_keeps_grapheme_clusters() {
+        let checkbox = Checkbox::new("Cafe\u{301} au lait", false)
+            .max_width(7)
+            .truncate(true);
+        assert_eq!(checkbox.to_lines(7), ["☐ Cafe\u{301}…"]);
+    }
+
+    #[test]
+    fn checkbox_inline_symbol() {
+        let checkbox = Checkbox::new("Enable {} advanced mode", true)
+            .inline_symbol(true)
+            .checkbox_style(Style::default().fg(Color::Green));
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 24, 1));
+        checkbox.clone().render(buffer.area, &mut buffer);
+
+        assert_eq!(buffer_rows(&buffer), ["Enable ☑ advanced mode"]);
+        assert_eq!(buffer[(7, 0)].fg, Color::Green);
+        assert_eq!(buffer[(6, 0)].fg, Color::Reset);
+        assert_eq!(checkbox.measure(40), (22, 1));
+    }
+
+    #[test]
+    fn checkbox_inline_symbol_ignores_label_position() {
+        let checkbox = Checkbox::new("A {} B", false)
+            .inline_symbol(true)
+            .label_position(LabelPosition::Bottom);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 8, 2));
+        checkbox.render(buffer.area, &mut buffer);
+        assert_eq!(buffer_rows(&buffer), ["A ☐ B", ""]);
+    }
+
+    #[test]
+    fn checkbox_inline_symbol_without_marker() {
+        let checkbox = Checkbox::new("Plain", true).inline_symbol(true);
+        assert_eq!(checkbox.to_plain_string(), "☑ Plain");
+
+        let checkbox = Checkbox::new("Keep {}", true);
+        assert_eq!(checkbox.to_plain_string(), "☑ Keep {}");
+    }
+
+    #[test]
+    fn checkbox_owned_outlives_its_source() {
+        struct Model {
+            options: Vec<Checkbox<'static>>,
         }
 
-        // Ensure render_area doesn't exceed original area
-        render_area.width = render_area.width.min(area.width);
+        let model = {
+            let label = format!("Option {}", 1);
+            let symbol = String::from("[x]");
+            Model {
+                options: vec![Checkbox::owned(label, true).checked_symbol(symbol)],
+            }
+        };
 
-        // Create checkbox and label spans
-        let checkbox_span = Span::styled(symbol.as_ref(), checkbox_style);
-        let styled_label = self.label.clone().patch_style(label_style);
-        let owned_label = Line::from(
-            styled_label
-                .spans
-                .iter()
-                .map(|s| Span::styled(s.content.to_string(), s.style))
-                .collect::<Vec<_>>(),
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 12, 1));
+        Widget::render(&model.options[0], buffer.area, &mut buffer);
+        assert_eq!(buffer_rows(&buffer), ["[x] Option 1"]);
+    }
+
+    #[test]
+    fn checkbox_auto_color() {
+        let render = |checkbox: Checkbox| {
+            let mut buffer = Buffer::empty(Rect::new(0, 0, 8, 1));
+            checkbox.render(buffer.area, &mut buffer);
+            (buffer[(0, 0)].fg, buffer[(2, 0)].fg)
+        };
+
+        let checkbox = Checkbox::new("Option", true).auto_color(true);
+        assert_eq!(render(checkbox.clone()), (Color::Green, Color::Reset));
+        assert_eq!(
+            render(checkbox.clone().checked(false)),
+            (Color::DarkGray, Color::Reset)
         );
+        assert_eq!(
+            render(checkbox.checkbox_style(Style::default().fg(Color::Blue))),
+            (Color::Blue, Color::Reset)
+        );
+    }
 
-        // Calculate dimensions based on label position
-        match self.label_position {
-            LabelPosition::Right | LabelPosition::Left => {
-                self.render_horizontal(render_area, buf, checkbox_span, owned_label);
-            }
-            LabelPosition::Top | LabelPosition::Bottom => {
-                self.render_vertical(render_area, buf, checkbox_span, owned_label);
-            }
+    #[test]
+    fn checkbox_symbol_frame() {
+        let checkbox = Checkbox::new("Option", true)
+            .symbol_frame("[ ", " ]")
+            .checkbox_style(Style::default().fg(Color::Green))
+            .frame_style(Style::default().fg(Color::DarkGray));
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 12, 1));
+        checkbox.clone().render(buffer.area, &mut buffer);
+
+        assert_eq!(buffer_rows(&buffer), ["[ ☑ ] Option"]);
+        for x in [0, 1, 3, 4] {
+            assert_eq!(buffer[(x, 0)].fg, Color::DarkGray);
         }
+        assert_eq!(buffer[(2, 0)].fg, Color::Green);
+        assert_eq!(buffer[(6, 0)].fg, Color::Reset);
+        assert_eq!(checkbox.measure(20), (12, 1));
     }
 
-    fn render_horizontal(
-        &self,
-        area: Rect,
-        buf: &mut Buffer,
-        checkbox_span: Span<'_>,
-        label: Line<'static>,
-    ) {
-        if area.height == 0 || area.width == 0 {
-            return;
+    #[test]
+    fn checkbox_max_width_narrower_than_symbol() {
+        let checkbox = Checkbox::new("Option", true).checked_symbol("[X]");
+        for (max_width, expected) in [(0, ""), (1, "["), (2, "[X")] {
+            let mut buffer = Buffer::empty(Rect::new(0, 0, 6, 1));
+            checkbox
+                .clone()
+                .max_width(max_width)
+                .render(buffer.area, &mut buffer);
+            assert_eq!(buffer_rows(&buffer), [expected], "max_width {max_width}");
+            assert_eq!(
+                checkbox.clone().max_width(max_width).measure(6),
+                (max_width, 1)
+            );
         }
 
-        let checkbox_width = checkbox_span.width() as u16;
-        let space_width = 1u16;
+        // A wide glyph that doesn't fit is left out rather than split
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 6, 1));
+        Checkbox::emoji("Option", true)
+            .max_width(1)
+            .render(buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::empty(Rect::new(0, 0, 6, 1)));
+    }
 
-        // Handle wrapping if enabled
-        let label_lines = if self.wrap_label {
-            let available_width = area.width.saturating_sub(checkbox_width + space_width);
-            Self::wrap_text(&label, available_width)
-        } else {
-            vec![label]
+    #[test]
+    fn checkbox_symbol_for_state() {
+        let checkbox = Checkbox::new("Option", false).symbols(symbols::SymbolSet::ascii());
+        assert_eq!(checkbox.symbol(), "[ ]");
+        assert_eq!(checkbox.clone().checked(true).symbol(), "[X]");
+        assert_eq!(checkbox.clone().indeterminate(true).symbol(), "[-]");
+        assert_eq!(checkbox.symbol_for(CheckState::Unchecked), "[ ]");
+        assert_eq!(checkbox.symbol_for(CheckState::Checked), "[X]");
+        assert_eq!(checkbox.symbol_for(CheckState::Indeterminate), "[-]");
+        assert_eq!(CheckState::default(), CheckState::Unchecked);
+    }
+
+    #[test]
+    fn checkbox_block_checked_style() {
+        let checkbox = Checkbox::new("On", true)
+            .block(Block::bordered().border_style(Style::default().bg(Color::Blue)))
+            .block_checked_style(Style::default().fg(Color::Green));
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 6, 3));
+        checkbox.clone().render(buffer.area, &mut buffer);
+
+        for position in [(0, 0), (5, 0), (0, 1), (5, 1), (3, 2)] {
+            assert_eq!(buffer[position].fg, Color::Green);
+            assert_eq!(buffer[position].bg, Color::Blue);
+        }
+        assert_eq!(buffer[(1, 1)].fg, Color::Reset);
+
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 6, 3));
+        checkbox.checked(false).render(buffer.area, &mut buffer);
+        assert_eq!(buffer[(0, 0)].fg, Color::Reset);
+    }
+
+    #[test]
+    fn checkbox_form_row() {
+        let checkbox = Checkbox::new("Enable dark mode", true).form_row();
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 20, 1));
+        checkbox.clone().render(buffer.area, &mut buffer);
+
+        assert_eq!(buffer, Buffer::with_lines(["Enable dark mode   ☑"]));
+        assert!(checkbox.hit_test(buffer.area, 17, 0));
+    }
+
+    #[test]
+    fn checkbox_into_owned_outlives_source() {
+        let borrowed_render = {
+            let mut buffer = Buffer::empty(Rect::new(0, 0, 16, 1));
+            let label = String::from("Option");
+            let symbol = String::from("[x]");
+            let checkbox = Checkbox::new(label.as_str(), true)
+                .checked_symbol(symbol.as_str())
+                .right_hint(Span::from(label.as_str()))
+                .label_style(Style::default().fg(Color::Green));
+            checkbox.render(buffer.area, &mut buffer);
+            buffer
         };
 
-        let total_width = if label_lines.is_empty() {
-            checkbox_width
-        } else {
-            checkbox_width
-                + space_width
-                + label_lines
-                    .iter()
-                    .map(|l| l.width() as u16)
-                    .max()
-                    .unwrap_or(0)
+        let owned: Checkbox<'static> = {
+            let label = String::from("Option");
+            let symbol = String::from("[x]");
+            Checkbox::new(label.as_str(), true)
+                .checked_symbol(symbol.as_str())
+                .right_hint(Span::from(label.as_str()))
+                .label_style(Style::default().fg(Color::Green))
+                .into_owned()
         };
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 16, 1));
+        owned.render(buffer.area, &mut buffer);
+        assert_eq!(buffer, borrowed_render);
+    }
 
-        // Calculate horizontal offset based on alignment
-        let x_offset = match self.horizontal_alignment {
-            HorizontalAlignment::Left => 0,
-            HorizontalAlignment::Center => area.width.saturating_sub(total_width) / 2,
-            HorizontalAlignment::Right => area.width.saturating_sub(total_width),
+    #[test]
+    fn checkbox_into_owned_drops_block() {
+        let checkbox = Checkbox::new("Option", true).block(Block::bordered());
+        assert_eq!(
+            checkbox.clone().into_owned(),
+            Checkbox {
+                block: None,
+                ..checkbox
+            }
+        );
+    }
+
+    #[test]
+    fn checkbox_description() {
+        let checkbox = Checkbox::new("Auto-save", true).description("On blur");
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 12, 3));
+        checkbox.clone().render(buffer.area, &mut buffer);
+
+        assert_eq!(buffer_rows(&buffer), ["☑ Auto-save", "  On blur", ""]);
+        assert_eq!(buffer[(2, 1)].fg, Color::DarkGray);
+        assert_eq!(buffer[(2, 0)].fg, Color::Reset);
+        assert_eq!(checkbox.measure(20), (11, 2));
+
+        let checkbox = checkbox.description_style(Style::default().fg(Color::Blue));
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 12, 2));
+        checkbox.render(buffer.area, &mut buffer);
+        assert_eq!(buffer[(2, 1)].fg, Color::Blue);
+    }
+
+    #[test]
+    fn checkbox_description_on_focus() {
+        let checkbox = Checkbox::new("Auto-save", true)
+            .description("On blur")
+            .description_on_focus(true);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 12, 2));
+        checkbox.clone().render(buffer.area, &mut buffer);
+        assert_eq!(buffer_rows(&buffer), ["☑ Auto-save", ""]);
+        assert_eq!(checkbox.measure(20), (11, 1));
+
+        let checkbox = checkbox.focused(true);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 12, 2));
+        checkbox.clone().render(buffer.area, &mut buffer);
+        assert_eq!(buffer_rows(&buffer), ["☑ Auto-save", "  On blur"]);
+        assert_eq!(checkbox.measure(20), (11, 2));
+    }
+
+    #[test]
+    fn checkbox_description_wraps_with_label() {
+        let checkbox = Checkbox::new("Auto save", true)
+            .description("Saves on blur")
+            .wrap_label(true);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 8, 4));
+        checkbox.clone().render(buffer.area, &mut buffer);
+
+        assert_eq!(
+            buffer_rows(&buffer),
+            ["☑ Auto", "  save", "  Saves", "  on"]
+        );
+        assert_eq!(checkbox.measure(8), (7, 5));
+    }
+
+    #[test]
+    fn checkbox_checked_symbol_line() {
+        let checkbox = Checkbox::new("Option", true)
+            .style(Style::default().bg(Color::Black))
+            .checked_symbol_line(Line::from(vec![
+                Span::styled("[", Style::default().fg(Color::Gray)),
+                Span::styled("X", Style::default().fg(Color::Red)),
+                Span::styled("]", Style::default().fg(Color::Gray)),
+            ]));
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 1));
+        checkbox.clone().render(buffer.area, &mut buffer);
+
+        assert_eq!(buffer_rows(&buffer), ["[X] Option"]);
+        assert_eq!(buffer[(0, 0)].fg, Color::Gray);
+        assert_eq!(buffer[(1, 0)].fg, Color::Red);
+        assert_eq!(buffer[(2, 0)].fg, Color::Gray);
+        assert_eq!(buffer[(1, 0)].bg, Color::Black);
+        assert_eq!(checkbox.symbol(), "[X]");
+
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 1));
+        checkbox.checked(false).render(buffer.area, &mut buffer);
+        assert_eq!(buffer_rows(&buffer), ["☐ Option"]);
+    }
+
+    #[test]
+    fn checkbox_checked_symbol_span() {
+        let checkbox = Checkbox::new("Option", true)
+            .checked_symbol_span(Span::styled("✔", Style::default().fg(Color::Green)));
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 8, 1));
+        checkbox.clone().render(buffer.area, &mut buffer);
+        assert_eq!(buffer_rows(&buffer), ["✔ Option"]);
+        assert_eq!(buffer[(0, 0)].fg, Color::Green);
+
+        // A plain symbol replaces the styled one
+        let checkbox = checkbox.checked_symbol("x");
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 8, 1));
+        checkbox.render(buffer.area, &mut buffer);
+        assert_eq!(buffer[(0, 0)].fg, Color::Reset);
+    }
+
+    #[test]
+    fn checkbox_content_eq_ignores_style() {
+        let checkbox = Checkbox::new("Option", true);
+        let styled = Checkbox::new(
+            Line::from(vec![
+                Span::styled("Opt", Style::default().fg(Color::Red)),
+                Span::raw("ion"),
+            ]),
+            true,
+        )
+        .style(Style::default().bg(Color::Blue))
+        .focused(true)
+        .block(Block::bordered())
+        .horizontal_alignment(HorizontalAlignment::Center);
+        assert!(checkbox.content_eq(&styled));
+        assert_ne!(checkbox, styled);
+
+        assert!(!checkbox.content_eq(&checkbox.clone().checked(false)));
+        assert!(!checkbox.content_eq(&checkbox.clone().label("Other")));
+        assert!(!checkbox.content_eq(&checkbox.clone().checked_symbol("[X]")));
+        assert!(!checkbox.content_eq(&checkbox.clone().indeterminate(true)));
+        assert!(!checkbox.content_eq(&checkbox.clone().pending(true)));
+        assert!(!checkbox.content_eq(&checkbox.clone().pending_symbol("...")));
+    }
+
+    #[test]
+    fn checkbox_symbol_vertical_align() {
+        let checkbox = Checkbox::new("One two three", true).wrap_label(true);
+        let area = Rect::new(0, 0, 7, 3);
+        let render = |checkbox: Checkbox| {
+            let mut buffer = Buffer::empty(area);
+            checkbox.render(area, &mut buffer);
+            buffer_rows(&buffer)
         };
 
-        // Calculate vertical offset based on alignment
-        let content_height = label_lines.len() as u16;
-        let y_offset = match self.vertical_alignment {
-            VerticalAlignment::Top => 0,
-            VerticalAlignment::Center => area.height.saturating_sub(content_height) / 2,
-            VerticalAlignment::Bottom => area.height.saturating_sub(content_height),
+        assert_eq!(render(checkbox.clone()), ["☑ One", "  two", "  three"]);
+        assert_eq!(
+            render(
+                checkbox
+                    .clone()
+                    .symbol_vertical_align(VerticalAlignment::Center)
+            ),
+            ["  One", "☑ two", "  three"]
+        );
+        assert_eq!(
+            render(checkbox.symbol_vertical_align(VerticalAlignment::Bottom)),
+            ["  One", "  two", "☑ three"]
+        );
+
+        let checkbox = Checkbox::default()
+            .checked(true)
+            .label_text(Text::from(vec![
+                Line::from("A"),
+                Line::from("B"),
+                Line::from("C"),
+            ]))
+            .symbol_vertical_align(VerticalAlignment::Top);
+        assert_eq!(render(checkbox), ["☑ A", "  B", "  C"]);
+    }
+
+    #[test]
+    fn checkbox_never_writes_outside_area() {
+        // A small xorshift generator keeps the cases reproducible without extra dependencies
+        let mut seed = 0x2545_f491_4f6c_dd1d_u64;
+        let mut next = move |n: u16| {
+            seed ^= seed << 13;
+            seed ^= seed >> 7;
+            seed ^= seed << 17;
+            (seed % u64::from(n)) as u16
         };
+        let labels = ["Option", "漢字 wide glyphs", "A ✅ B ✅ C", "", "x\ty"];
+        let positions = [
+            LabelPosition::Right,
+            LabelPosition::Left,
+            LabelPosition::Top,
+            LabelPosition::Bottom,
+        ];
+        let horizontal = [
+            HorizontalAlignment::Left,
+            HorizontalAlignment::Center,
+            HorizontalAlignment::Right,
+        ];
+        let vertical = [
+            VerticalAlignment::Top,
+            VerticalAlignment::Center,
+            VerticalAlignment::Bottom,
+        ];
 
-        // Render based on label position
-        match self.label_position {
-            LabelPosition::Right => {
-                // Render checkbox first, then label
-                if x_offset < area.width && y_offset < area.height {
-                    let checkbox_area = Rect {
-                        x: area.x + x_offset,
-                        y: area.y + y_offset,
-                        width: checkbox_width.min(area.width.saturating_sub(x_offset)),
-                        height: 1,
-                    };
-                    Line::from(vec![checkbox_span]).render(checkbox_area, buf);
-
-                    // Render label lines
-                    for (i, label_line) in label_lines.iter().enumerate() {
-                        let label_x = area.x + x_offset + checkbox_width + space_width;
-                        let label_y = area.y + y_offset + i as u16;
-                        if label_y < area.y + area.height && label_x < area.x + area.width {
-                            let label_area = Rect {
-                                x: label_x,
-                                y: label_y,
-                                width: area
-                                    .width
-                                    .saturating_sub(x_offset + checkbox_width + space_width),
-                                height: 1,
-                            };
-                            label_line.clone().render(label_area, buf);
-                        }
-                    }
-                }
+        for case in 0..5000 {
+            let mut checkbox = Checkbox::new(labels[usize::from(next(5))], next(2) == 0)
+                .label_position(positions[usize::from(next(4))])
+                .horizontal_alignment(horizontal[usize::from(next(3))])
+                .vertical_alignment(vertical[usize::from(next(3))])
+                .wrap_label(next(2) == 0)
+                .spacing(next(3))
+                .rtl(next(4) == 0)
+                .pill(next(4) == 0)
+                .focus_corners(next(6) == 0)
+                .focused(next(2) == 0)
+                .double_width_label(next(6) == 0)
+                .clip_indicator(next(2) == 0)
+                .indent(next(3))
+                .tree_guides(next(2) == 0)
+                .scroll(next(3));
+            if next(2) == 0 {
+                checkbox = checkbox.symbol_alignment(horizontal[usize::from(next(3))]);
+            }
+            if next(3) == 0 {
+                checkbox = checkbox.checked_symbol("✅").unchecked_symbol("[ ]");
+            }
+            if next(3) == 0 {
+                checkbox = checkbox.block(Block::bordered().title("Title"));
+            }
+            if next(3) == 0 {
+                checkbox = checkbox.min_width(next(30));
+            }
+            if next(3) == 0 {
+                checkbox = checkbox.max_width(next(30));
+            }
+            if next(4) == 0 {
+                checkbox = checkbox.content_width(next(20));
+            }
+            if next(4) == 0 {
+                checkbox = checkbox.right_hint("^S").prefix("‹").suffix("漢");
+            }
+            if next(4) == 0 {
+                checkbox = checkbox.symbol_frame("[", "]");
+            }
+            if next(4) == 0 {
+                checkbox = checkbox.description("Description 漢字");
+            }
+            if next(4) == 0 {
+                checkbox = checkbox.label_value("Key", "値");
+            }
+            if next(4) == 0 {
+                checkbox = checkbox.sub_options(vec![("Sub 漢".to_string(), true)]);
             }
-            LabelPosition::Left => {
-                // Render label first, then checkbox
-                let max_label_width = label_lines
-                    .iter()
-                    .map(|l| l.width() as u16)
-                    .max()
-                    .unwrap_or(0);
-
-                // Render label lines
-                for (i, label_line) in label_lines.iter().enumerate() {
-                    let label_y = area.y + y_offset + i as u16;
-                    if label_y < area.y + area.height && x_offset < area.width {
-                        let label_area = Rect {
-                            x: area.x + x_offset,
-                            y: label_y,
-                            width: max_label_width.min(area.width.saturating_sub(x_offset)),
-                            height: 1,
-                        };
-                        label_line.clone().render(label_area, buf);
-                    }
-                }
 
-                // Render checkbox
-                let checkbox_x = area.x + x_offset + max_label_width + space_width;
-                if checkbox_x < area.x + area.width && y_offset < area.height {
-                    let checkbox_area = Rect {
-                        x: checkbox_x,
-                        y: area.y + y_offset,
-                        width: checkbox_width.min(
-                            area.width
-                                .saturating_sub(x_offset + max_label_width + space_width),
-                        ),
-                        height: 1,
-                    };
-                    Line::from(vec![checkbox_span]).render(checkbox_area, buf);
+            let area = Rect::new(3, 2, next(24), next(6));
+            let mut buffer = Buffer::empty(Rect::new(0, 0, 32, 12));
+            checkbox.clone().render(area, &mut buffer);
+            for position in buffer.area.positions() {
+                if !area.contains(position) {
+                    assert_eq!(
+                        buffer[position],
+                        Cell::default(),
+                        "case {case} wrote {position:?} outside {area:?}: {checkbox:?}"
+                    );
                 }
             }
-            _ => {}
         }
     }
 
-    fn render_vertical(
-        &self,
-        area: Rect,
-        buf: &mut Buffer,
-        checkbox_span: Span<'_>,
-        label: Line<'static>,
-    ) {
-        if area.height == 0 || area.width == 0 {
-            return;
+    #[test]
+    fn checkbox_when() {
+        let checkbox = Checkbox::new("Option", false);
+        let highlight =
+            |checkbox: Checkbox<'static>| checkbox.style(Style::default().bg(Color::Blue));
+        assert_eq!(
+            checkbox.clone().when(true, highlight),
+            checkbox.clone().style(Style::default().bg(Color::Blue))
+        );
+        assert_eq!(checkbox.clone().when(false, highlight), checkbox);
+    }
+
+    #[test]
+    fn checkbox_monochrome() {
+        let checkbox = Checkbox::new("Option", true)
+            .block(Block::bordered().border_style(Style::default().fg(Color::Blue)))
+            .checkbox_style(
+                Style::default()
+                    .fg(Color::Green)
+                    .add_modifier(Modifier::BOLD),
+            )
+            .label_style(Style::default().bg(Color::Red).underlined())
+            .monochrome(true);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 3));
+        checkbox.clone().render(buffer.area, &mut buffer);
+
+        assert_eq!(
+            buffer_rows(&buffer),
+            ["┌────────┐", "│☑ Option│", "└────────┘"]
+        );
+        for cell in &buffer.content {
+            assert_eq!((cell.fg, cell.bg), (Color::Reset, Color::Reset));
         }
+        assert!(buffer[(1, 1)].modifier.contains(Modifier::BOLD));
+        assert!(buffer[(3, 1)].modifier.contains(Modifier::UNDERLINED));
 
-        // Handle wrapping if enabled
-        let label_lines = if self.wrap_label {
-            Self::wrap_text(&label, area.width)
-        } else {
-            vec![label]
-        };
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 3));
+        checkbox.monochrome(false).render(buffer.area, &mut buffer);
+        assert_eq!(buffer[(1, 1)].fg, Color::Green);
+    }
 
-        let checkbox_width = checkbox_span.width() as u16;
-        let label_height = label_lines.len() as u16;
-        let total_height = 1 + label_height; // checkbox + label lines
+    #[test]
+    fn checkbox_monochrome_keeps_buffer_colors() {
+        let panel = Style::default().fg(Color::White).bg(Color::Blue);
+        let checkboxes = [
+            Checkbox::new("Option", true),
+            Checkbox::new("Option", true)
+                .block(Block::bordered().style(Style::default().bg(Color::Red)))
+                .style(Style::default().bg(Color::Green))
+                .checkbox_style(Style::default().fg(Color::Yellow).bold())
+                .focused(true)
+                .right_hint(Span::styled("^O", Style::default().fg(Color::Cyan))),
+        ];
+        for checkbox in checkboxes {
+            let mut buffer = Buffer::empty(Rect::new(0, 0, 14, 3));
+            buffer.set_style(buffer.area, panel);
+            checkbox.monochrome(true).render(buffer.area, &mut buffer);
 
-        // Calculate vertical offset
-        let y_offset = match self.vertical_alignment {
-            VerticalAlignment::Top => 0,
-            VerticalAlignment::Center => area.height.saturating_sub(total_height) / 2,
-            VerticalAlignment::Bottom => area.height.saturating_sub(total_height),
+            for cell in &buffer.content {
+                assert_eq!((cell.fg, cell.bg), (Color::White, Color::Blue));
+            }
+        }
+    }
+
+    #[test]
+    fn checkbox_label_transform() {
+        let label = Line::from(vec![
+            Span::styled("adVanced ", Style::default().fg(Color::Red)),
+            Span::raw("opt"),
+            Span::styled("ions", Style::default().fg(Color::Blue)),
+        ]);
+        let checkbox = Checkbox::new(label.clone(), false).label_transform(LabelCase::Upper);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 18, 1));
+        checkbox.clone().render(buffer.area, &mut buffer);
+
+        assert_eq!(buffer_rows(&buffer), ["☐ ADVANCED OPTIONS"]);
+        assert_eq!(buffer[(2, 0)].fg, Color::Red);
+        assert_eq!(buffer[(17, 0)].fg, Color::Blue);
+        assert_eq!(checkbox.label, Text::from(label));
+
+        let checkbox = checkbox.label_transform(LabelCase::Lower);
+        assert_eq!(checkbox.to_plain_string(), "☐ advanced options");
+        let checkbox = checkbox.label_transform(LabelCase::Title);
+        assert_eq!(checkbox.to_plain_string(), "☐ Advanced Options");
+    }
+
+    #[test]
+    fn checkbox_empty_label_touches_only_symbol() {
+        let positions = [
+            LabelPosition::Right,
+            LabelPosition::Left,
+            LabelPosition::Top,
+            LabelPosition::Bottom,
+        ];
+        for position in positions {
+            for checkbox in [
+                Checkbox::new("", true),
+                Checkbox::new("", true).separator(" | "),
+                Checkbox::new("", true).label_style(Style::default().bg(Color::Red)),
+            ] {
+                let checkbox = checkbox.label_position(position);
+                let mut buffer = Buffer::empty(Rect::new(0, 0, 5, 3));
+                checkbox.clone().render(buffer.area, &mut buffer);
+
+                let mut expected = Buffer::empty(buffer.area);
+                expected[(0, 0)].set_symbol("☑");
+                assert_eq!(buffer, expected, "{checkbox:?}");
+                assert_eq!(checkbox.measure(5), (1, 1));
+            }
+        }
+    }
+
+    #[test]
+    fn checkbox_to_spans() {
+        let checkbox = Checkbox::new(
+            Line::from(vec![
+                Span::raw("Dark "),
+                Span::styled("mode", Style::new().bold()),
+            ]),
+            true,
+        )
+        .checkbox_style(Style::default().fg(Color::Green))
+        .label_style(Style::default().fg(Color::White).bg(Color::Blue));
+        assert_eq!(
+            checkbox.to_spans(),
+            [
+                Span::styled("☑", Style::default().fg(Color::Green)),
+                Span::styled(" ", Style::default().bg(Color::Blue)),
+                Span::styled("Dark ", Style::default().fg(Color::White).bg(Color::Blue)),
+                Span::styled(
+                    "mode",
+                    Style::default().fg(Color::White).bg(Color::Blue).bold()
+                ),
+            ]
+        );
+
+        let spans = checkbox
+            .label_position(LabelPosition::Left)
+            .separator(": ")
+            .to_spans();
+        let text: Vec<_> = spans.iter().map(|span| span.content.as_ref()).collect();
+        assert_eq!(text, ["Dark ", "mode", ": ", "☑"]);
+        assert_eq!(spans[2].style.bg, Some(Color::Blue));
+
+        // Right-to-left mirrors the order, as rendering does
+        let rtl = Checkbox::new("Option", true).rtl(true);
+        let text: Vec<_> = rtl
+            .to_spans()
+            .into_iter()
+            .map(|span| span.content)
+            .collect();
+        assert_eq!(text, ["Option", " ", "☑"]);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 8, 1));
+        rtl.clone().render(buffer.area, &mut buffer);
+        assert_eq!(buffer_rows(&buffer)[0].trim(), text.concat());
+        let text: Vec<_> = rtl
+            .label_position(LabelPosition::Left)
+            .to_spans()
+            .into_iter()
+            .map(|span| span.content)
+            .collect();
+        assert_eq!(text, ["☑", " ", "Option"]);
+
+        assert_eq!(Checkbox::new("", false).to_spans(), [Span::raw("☐")]);
+    }
+
+    #[test]
+    fn checkbox_is_valid() {
+        assert!(Checkbox::new("Subscribe", false).is_valid());
+        assert!(Checkbox::new("Subscribe", true).is_valid());
+        assert!(!Checkbox::new("Accept terms", false)
+            .required(true)
+            .is_valid());
+        assert!(Checkbox::new("Accept terms", true)
+            .required(true)
+            .is_valid());
+    }
+
+    #[test]
+    fn checkbox_required_marker_survives_truncation() {
+        let checkbox = Checkbox::new("Accept the terms", false).required(true);
+        for checkbox in [
+            checkbox.clone().truncate(true),
+            checkbox.truncate_keep_suffix(5),
+        ] {
+            for width in 3..=5 {
+                let mut buffer = Buffer::empty(Rect::new(0, 0, width + 2, 1));
+                checkbox.clone().render(buffer.area, &mut buffer);
+                let row = &buffer_rows(&buffer)[0];
+                assert!(row.starts_with("☐ *"), "{row:?} at width {width}");
+                assert!(row.contains('…'), "{row:?} at width {width}");
+            }
+        }
+    }
+
+    #[test]
+    fn checkbox_required_marker() {
+        let checkbox = Checkbox::new("Accept terms", false).required(true);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 16, 1));
+        checkbox.clone().render(buffer.area, &mut buffer);
+
+        assert_eq!(buffer_rows(&buffer), ["☐ * Accept terms"]);
+        assert_eq!(buffer[(2, 0)].fg, Color::Red);
+        assert_eq!(buffer[(4, 0)].fg, Color::Reset);
+        assert_eq!(checkbox.measure(20), (16, 1));
+
+        // The marker goes away once the checkbox is checked, or when it is optional
+        for checkbox in [
+            checkbox.clone().checked(true),
+            Checkbox::new("Accept terms", false),
+        ] {
+            let mut buffer = Buffer::empty(Rect::new(0, 0, 16, 1));
+            checkbox.render(buffer.area, &mut buffer);
+            assert!(!buffer_rows(&buffer)[0].contains('*'));
+        }
+    }
+
+    #[test]
+    fn checkbox_list_preserves_order() {
+        let checkboxes = checkbox_list([("A", true), ("B", false), ("C", true)]);
+        assert_eq!(
+            checkboxes,
+            [
+                Checkbox::new("A", true),
+                Checkbox::new("B", false),
+                Checkbox::new("C", true),
+            ]
+        );
+    }
+
+    #[test]
+    fn checkbox_render_frame() {
+        let checkbox = Checkbox::new("A", true).transition_symbols(["◔", "◑", "◕"]);
+        let frame = |checkbox: &Checkbox, progress| {
+            let mut buffer = Buffer::empty(Rect::new(0, 0, 3, 1));
+            checkbox.render_frame(buffer.area, &mut buffer, progress);
+            buffer
         };
 
-        match self.label_position {
-            LabelPosition::Top => {
-                // Render label first
-                for (i, label_line) in label_lines.iter().enumerate() {
-                    let label_y = area.y + y_offset + i as u16;
-                    if label_y < area.y + area.height {
-                        let x_offset = match self.horizontal_alignment {
-                            HorizontalAlignment::Left => 0,
-                            HorizontalAlignment::Center => {
-                                area.width.saturating_sub(label_line.width() as u16) / 2
-                            }
-                            HorizontalAlignment::Right => {
-                                area.width.saturating_sub(label_line.width() as u16)
-                            }
-                        };
-                        let label_area = Rect {
-                            x: area.x + x_offset,
-                            y: label_y,
-                            width: area.width.saturating_sub(x_offset),
-                            height: 1,
-                        };
-                        label_line.clone().render(label_area, buf);
-                    }
-                }
+        assert_eq!(frame(&checkbox, 0.0), Buffer::with_lines(["☐ A"]));
+        assert_eq!(frame(&checkbox, 0.25), Buffer::with_lines(["◔ A"]));
+        assert_eq!(frame(&checkbox, 0.5), Buffer::with_lines(["◑ A"]));
+        assert_eq!(frame(&checkbox, 1.0), Buffer::with_lines(["☑ A"]));
+
+        let checkbox = checkbox.checked(false);
+        assert_eq!(frame(&checkbox, 0.0), Buffer::with_lines(["☑ A"]));
+        assert_eq!(frame(&checkbox, 0.25), Buffer::with_lines(["◕ A"]));
+        assert_eq!(frame(&checkbox, 1.0), Buffer::with_lines(["☐ A"]));
+    }
+
+    #[test]
+    fn checkbox_render_frame_overrides_symbol_state() {
+        let checkbox = Checkbox::new("A", true).transition_symbols(["◔", "◑", "◕"]);
+        let checkboxes = [
+            checkbox
+                .clone()
+                .checked_symbol_line(Line::styled("[x]", Style::default().fg(Color::Red))),
+            checkbox.clone().pending(true),
+            checkbox.partial(0.5),
+        ];
+        for checkbox in checkboxes {
+            let mut buffer = Buffer::empty(Rect::new(0, 0, 3, 1));
+            checkbox.render_frame(buffer.area, &mut buffer, 0.5);
+            assert_eq!(buffer_rows(&buffer), ["◑ A"], "{checkbox:?}");
+        }
+    }
+
+    #[test]
+    fn checkbox_render_frame_without_symbols() {
+        let checkbox = Checkbox::new("A", true);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 3, 1));
+        checkbox.render_frame(buffer.area, &mut buffer, 0.4);
+        assert_eq!(buffer, Buffer::with_lines(["☐ A"]));
+        checkbox.render_frame(buffer.area, &mut buffer, 0.6);
+        assert_eq!(buffer, Buffer::with_lines(["☑ A"]));
+    }
+
+    #[test]
+    fn checkbox_spacing() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 8, 1));
+        Checkbox::new("Test", true)
+            .spacing(0)
+            .render(buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(["☑Test   "]));
+
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 8, 1));
+        Checkbox::new("Test", true)
+            .spacing(3)
+            .render(buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(["☑   Test"]));
+        assert_eq!(Checkbox::new("Test", true).spacing(3).measure(20), (8, 1));
+    }
+
+    #[test]
+    fn checkbox_separator() {
+        let checkbox = Checkbox::new("Test", true)
+            .separator(" - ")
+            .label_style(Style::default().fg(Color::Green));
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 9, 1));
+        checkbox.clone().render(buffer.area, &mut buffer);
+
+        let mut expected = Buffer::with_lines(["☑ - Test "]);
+        expected.set_style(Rect::new(1, 0, 7, 1), Style::default().fg(Color::Green));
+        assert_eq!(buffer, expected);
+        assert_eq!(checkbox.measure(20), (8, 1));
+
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 9, 1));
+        Checkbox::new("Test", true)
+            .separator(" - ")
+            .label_position(LabelPosition::Left)
+            .render(buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(["Test - ☑ "]));
+    }
+
+    #[test]
+    fn checkbox_separator_skipped_for_empty_label() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 4, 1));
+        Checkbox::new("", true)
+            .separator(" - ")
+            .render(buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(["☑   "]));
+    }
+
+    #[test]
+    fn checkbox_rtl_mirrors_layout() {
+        let checkbox = Checkbox::new("Test", true).rtl(true);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 12, 1));
+        checkbox.render(buffer.area, &mut buffer);
+
+        assert_eq!(buffer, Buffer::with_lines(["      Test ☑"]));
+    }
+
+    #[test]
+    fn checkbox_rtl_right_aligns_wrapped_lines() {
+        let checkbox = Checkbox::new("aa bbbb", true).wrap_label(true).rtl(true);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 6, 2));
+        checkbox.render(buffer.area, &mut buffer);
 
-                // Render checkbox
-                let checkbox_y = area.y + y_offset + label_height;
-                if checkbox_y < area.y + area.height {
-                    let x_offset = match self.horizontal_alignment {
-                        HorizontalAlignment::Left => 0,
-                        HorizontalAlignment::Center => {
-                            area.width.saturating_sub(checkbox_width) / 2
-                        }
-                        HorizontalAlignment::Right => area.width.saturating_sub(checkbox_width),
-                    };
-                    let checkbox_area = Rect {
-                        x: area.x + x_offset,
-                        y: checkbox_y,
-                        width: checkbox_width.min(area.width.saturating_sub(x_offset)),
-                        height: 1,
-                    };
-                    Line::from(vec![checkbox_span]).render(checkbox_area, buf);
-                }
-            }
-            LabelPosition::Bottom => {
-                // Render checkbox first
-                let x_offset = match self.horizontal_alignment {
-                    HorizontalAlignment::Left => 0,
-                    HorizontalAlignment::Center => area.width.saturating_sub(checkbox_width) / 2,
-                    HorizontalAlignment::Right => area.width.saturating_sub(checkbox_width),
-                };
-                let checkbox_area = Rect {
-                    x: area.x + x_offset,
-                    y: area.y + y_offset,
-                    width: checkbox_width.min(area.width.saturating_sub(x_offset)),
-                    height: 1,
-                };
-                Line::from(vec![checkbox_span]).render(checkbox_area, buf);
-
-                // Render label
-                for (i, label_line) in label_lines.iter().enumerate() {
-                    let label_y = area.y + y_offset + 1 + i as u16;
-                    if label_y < area.y + area.height {
-                        let x_offset = match self.horizontal_alignment {
-                            HorizontalAlignment::Left => 0,
-                            HorizontalAlignment::Center => {
-                                area.width.saturating_sub(label_line.width() as u16) / 2
-                            }
-                            HorizontalAlignment::Right => {
-                                area.width.saturating_sub(label_line.width() as u16)
-                            }
-                        };
-                        let label_area = Rect {
-                            x: area.x + x_offset,
-                            y: label_y,
-                            width: area.width.saturating_sub(x_offset),
-                            height: 1,
-                        };
-                        label_line.clone().render(label_area, buf);
-                    }
-                }
-            }
-            _ => {}
-        }
+        assert_eq!(buffer, Buffer::with_lines(["  aa ☑", "bbbb  "]));
     }
 
-    fn wrap_text(line: &Line<'_>, max_width: u16) -> Vec<Line<'static>> {
-        if max_width == 0 {
-            let owned = Line::from(
-                line.spans
-                    .iter()
-                    .map(|s| Span::styled(s.content.to_string(), s.style))
-                    .collect::<Vec<_>>(),
-            );
-            return vec![owned];
-        }
+    #[test]
+    fn checkbox_focus_corners() {
+        let checkbox = Checkbox::new("Test", true)
+            .focus_corners(true)
+            .focused(true);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 9, 3));
+        checkbox.render(buffer.area, &mut buffer);
 
-        let mut result = Vec::new();
-        let mut current_line = Vec::new();
-        let mut current_width = 0u16;
+        let mut expected = Buffer::with_lines(["⌜       ⌝", " ☑ Test  ", "⌞       ⌟"]);
+        expected.set_style(Rect::new(1, 1, 1, 1), Modifier::UNDERLINED);
+        expected.set_style(Rect::new(3, 1, 4, 1), Modifier::UNDERLINED);
+        assert_eq!(buffer, expected);
+    }
 
-        for span in &line.spans {
-            let text = span.content.as_ref();
-            let words: Vec<&str> = text.split(' ').collect();
-
-            for (i, word) in words.iter().enumerate() {
-                let word_width = word.chars().count() as u16;
-                let space_width = u16::from(i > 0 || !current_line.is_empty());
-
-                if current_width + space_width + word_width > max_width && !current_line.is_empty()
-                {
-                    result.push(Line::from(current_line.clone()));
-                    current_line.clear();
-                    current_width = 0;
-                }
+    #[test]
+    fn checkbox_focus_corners_unfocused_keeps_margin() {
+        let checkbox = Checkbox::new("Test", true).focus_corners(true);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 9, 3));
+        checkbox.render(buffer.area, &mut buffer);
 
-                if i > 0 {
-                    current_line.push(Span::styled(String::from(" "), span.style));
-                    current_width += 1;
-                }
+        assert_eq!(
+            buffer,
+            Buffer::with_lines(["         ", " ☑ Test  ", "         "])
+        );
+    }
 
-                current_line.push(Span::styled(String::from(*word), span.style));
-                current_width += word_width;
-            }
-        }
+    #[test]
+    fn checkbox_double_width_label() {
+        let checkbox = Checkbox::new("Test", false).double_width_label(true);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 12, 1));
+        checkbox.render(buffer.area, &mut buffer);
 
-        if !current_line.is_empty() {
-            result.push(Line::from(current_line));
-        }
+        assert_eq!(buffer, Buffer::with_lines(["☐ T e s t   "]));
+        let label = Checkbox::double_width(&Line::from("Test"));
+        assert_eq!(label.width(), 2 * "Test".len());
+    }
 
-        if result.is_empty() {
-            let owned = Line::from(
-                line.spans
-                    .iter()
-                    .map(|s| Span::styled(s.content.to_string(), s.style))
-                    .collect::<Vec<_>>(),
-            );
-            result.push(owned);
-        }
+    #[test]
+    fn checkbox_measure_positions() {
+        let checkbox = Checkbox::new("Test", false);
+        assert_eq!(checkbox.clone().measure(20), (6, 1));
+        assert_eq!(
+            checkbox
+                .clone()
+                .label_position(LabelPosition::Left)
+                .measure(20),
+            (6, 1)
+        );
+        assert_eq!(
+            checkbox
+                .clone()
+                .label_position(LabelPosition::Top)
+                .measure(20),
+            (4, 2)
+        );
+        assert_eq!(
+            checkbox.label_position(LabelPosition::Bottom).measure(20),
+            (4, 2)
+        );
+    }
 
-        result
+    #[test]
+    fn checkbox_measure_wrapped() {
+        let checkbox = Checkbox::new("aa bb cc", false).wrap_label(true);
+        assert_eq!(checkbox.clone().measure(20), (10, 1));
+        assert_eq!(checkbox.clone().measure(6), (4, 3));
+        assert_eq!(
+            checkbox.label_position(LabelPosition::Bottom).measure(5),
+            (5, 3)
+        );
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use ratatui::style::{Color, Modifier, Stylize};
+    #[test]
+    fn checkbox_measure_unwrapped_is_clamped() {
+        let checkbox = Checkbox::new("aa bb cc", false);
+        assert_eq!(checkbox.measure(6), (6, 1));
+    }
 
-    use super::*;
+    #[test]
+    fn checkbox_measure_with_block_and_symbol_width() {
+        let checkbox = Checkbox::new("Test", true)
+            .checked_symbol("[X]")
+            .block(Block::bordered());
+        assert_eq!(checkbox.measure(20), (10, 3));
+    }
 
     #[test]
-    fn checkbox_new() {
-        let checkbox = Checkbox::new("Test", true);
-        assert_eq!(checkbox.label, Line::from("Test"));
-        assert!(checkbox.checked);
+    fn checkbox_label_shorthands() {
+        let checkbox = Checkbox::new("Test", true).label_bold().label_red();
+        assert_eq!(
+            checkbox.label_style,
+            Style::default().fg(Color::Red).add_modifier(Modifier::BOLD)
+        );
+        assert_eq!(checkbox.style, Style::default());
+        assert_eq!(checkbox.checkbox_style, Style::default());
     }
 
     #[test]
-    fn checkbox_default() {
-        let checkbox = Checkbox::default();
-        assert_eq!(checkbox.label, Line::default());
-        assert!(!checkbox.checked);
+    fn checkbox_content_width() {
+        let checkbox = Checkbox::new("Test", true)
+            .content_width(8)
+            .horizontal_alignment(HorizontalAlignment::Center);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 12, 1));
+        (&checkbox).render(buffer.area, &mut buffer);
+
+        assert_eq!(buffer, Buffer::with_lines(["  Test   ☑  "]));
+        assert_eq!(checkbox.measure(12), (8, 1));
     }
 
     #[test]
-    fn checkbox_label() {
-        let checkbox = Checkbox::default().label("New label");
-        assert_eq!(checkbox.label, Line::from("New label"));
+    fn checkbox_content_width_left_aligned() {
+        let checkbox = Checkbox::new("Test", true).content_width(8);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 12, 1));
+        checkbox.render(buffer.area, &mut buffer);
+
+        assert_eq!(buffer, Buffer::with_lines(["Test   ☑    "]));
     }
 
     #[test]
-    fn checkbox_checked() {
-        let checkbox = Checkbox::default().checked(true);
-        assert!(checkbox.checked);
+    fn checkbox_hit_test_right() {
+        let checkbox = Checkbox::new("Test", false);
+        let area = Rect::new(2, 1, 12, 1);
+
+        assert!(checkbox.hit_test(area, 2, 1)); // symbol
+        assert!(!checkbox.hit_test(area, 3, 1)); // gap
+        assert!(checkbox.hit_test(area, 4, 1)); // label
+        assert!(checkbox.hit_test(area, 7, 1));
+        assert!(!checkbox.hit_test(area, 8, 1)); // padding
+        assert!(!checkbox.hit_test(area, 2, 0)); // outside the area
     }
 
     #[test]
-    fn checkbox_style() {
-        let style = Style::default().fg(Color::Red);
-        let checkbox = Checkbox::default().style(style);
-        assert_eq!(checkbox.style, style);
+    fn checkbox_hit_test_left() {
+        let checkbox = Checkbox::new("Test", false)
+            .label_position(LabelPosition::Left)
+            .horizontal_alignment(HorizontalAlignment::Right);
+        let area = Rect::new(0, 0, 10, 1);
+
+        assert!(!checkbox.hit_test(area, 3, 0)); // padding
+        assert!(checkbox.hit_test(area, 4, 0)); // label
+        assert!(!checkbox.hit_test(area, 8, 0)); // gap
+        assert!(checkbox.hit_test(area, 9, 0)); // symbol
     }
 
     #[test]
-    fn checkbox_checkbox_style() {
-        let style = Style::default().fg(Color::Green);
-        let checkbox = Checkbox::default().checkbox_style(style);
-        assert_eq!(checkbox.checkbox_style, style);
+    fn checkbox_hit_test_top() {
+        let checkbox = Checkbox::new("Test", false)
+            .label_position(LabelPosition::Top)
+            .horizontal_alignment(HorizontalAlignment::Center);
+        let area = Rect::new(0, 0, 8, 3);
+
+        assert!(checkbox.hit_test(area, 2, 0)); // label
+        assert!(!checkbox.hit_test(area, 1, 0)); // padding
+        assert!(checkbox.hit_test(area, 3, 1)); // symbol
+        assert!(!checkbox.hit_test(area, 2, 1));
+        assert!(!checkbox.hit_test(area, 3, 2)); // empty row
     }
 
     #[test]
-    fn checkbox_label_style() {
-        let style = Style::default().fg(Color::Blue);
-        let checkbox = Checkbox::default().label_style(style);
-        assert_eq!(checkbox.label_style, style);
+    fn checkbox_hit_test_bottom() {
+        let checkbox = Checkbox::new("Test", false)
+            .label_position(LabelPosition::Bottom)
+            .vertical_alignment(VerticalAlignment::Bottom);
+        let area = Rect::new(0, 0, 8, 3);
+
+        assert!(!checkbox.hit_test(area, 0, 0)); // padding
+        assert!(checkbox.hit_test(area, 0, 1)); // symbol
+        assert!(!checkbox.hit_test(area, 1, 1));
+        assert!(checkbox.hit_test(area, 3, 2)); // label
+        assert!(!checkbox.hit_test(area, 4, 2));
     }
 
     #[test]
-    fn checkbox_checked_symbol() {
-        let checkbox = Checkbox::default().checked_symbol("[X]");
-        assert_eq!(checkbox.checked_symbol, "[X]");
+    fn checkbox_hit_test_block() {
+        let checkbox = Checkbox::new("Test", false).block(Block::bordered());
+        let area = Rect::new(0, 0, 10, 3);
+
+        assert!(!checkbox.hit_test(area, 0, 0)); // border
+        assert!(!checkbox.hit_test(area, 1, 0));
+        assert!(checkbox.hit_test(area, 1, 1)); // symbol
+        assert!(checkbox.hit_test(area, 3, 1)); // label
+        assert!(!checkbox.hit_test(area, 9, 1));
     }
 
     #[test]
-    fn checkbox_unchecked_symbol() {
-        let checkbox = Checkbox::default().unchecked_symbol("[ ]");
-        assert_eq!(checkbox.unchecked_symbol, "[ ]");
+    fn checkbox_right_hint() {
+        for label in ["Hide", "Show hidden files", "A label far too long to fit"] {
+            let checkbox = Checkbox::new(label, false).right_hint("^H");
+            let mut buffer = Buffer::empty(Rect::new(0, 0, 16, 1));
+            checkbox.render(buffer.area, &mut buffer);
+
+            assert_eq!(buffer[(14, 0)].symbol(), "^");
+            assert_eq!(buffer[(15, 0)].symbol(), "H");
+            assert_eq!(buffer[(13, 0)].symbol(), " ");
+        }
     }
 
     #[test]
-    fn checkbox_styled_trait() {
-        let checkbox = Checkbox::default().red();
-        assert_eq!(checkbox.style, Style::default().fg(Color::Red));
+    fn checkbox_right_hint_measure() {
+        let checkbox = Checkbox::new("Test", false).right_hint("^T");
+        assert_eq!(checkbox.measure(40), (9, 1));
+
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 9, 1));
+        (&checkbox).render(buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(["☐ Test ^T"]));
+        assert!(checkbox.hit_test(buffer.area, 8, 0));
     }
 
     #[test]
-    fn checkbox_render_unchecked() {
-        let checkbox = Checkbox::new("Test", false);
+    fn checkbox_min_width_larger_than_area() {
+        let checkbox = Checkbox::new("Test", true)
+            .min_width(20)
+            .horizontal_alignment(HorizontalAlignment::Right);
         let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 1));
-        checkbox.render(buffer.area, &mut buffer);
+        (&checkbox).render(buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(["    ☑ Test"]));
 
-        // The buffer should contain the unchecked symbol followed by space and label
-        assert!(buffer
-            .cell(buffer.area.as_position())
-            .unwrap()
-            .symbol()
-            .starts_with('☐'));
+        let checkbox = checkbox.horizontal_alignment(HorizontalAlignment::Center);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 1));
+        checkbox.render(buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(["  ☑ Test  "]));
     }
 
     #[test]
-    fn checkbox_render_checked() {
-        let checkbox = Checkbox::new("Test", true);
+    fn checkbox_clip_indicator() {
+        let checkbox = Checkbox::new("Long label", true)
+            .min_width(20)
+            .clip_indicator(true);
         let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 1));
+        (&checkbox).render(buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(["☑ Long la›"]));
+
+        // No indicator when the minimum width fits
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 20, 1));
         checkbox.render(buffer.area, &mut buffer);
+        assert_eq!(buffer[(19, 0)].symbol(), " ");
+    }
 
-        // The buffer should contain the checked symbol followed by space and label
-        assert!(buffer
-            .cell(buffer.area.as_position())
-            .unwrap()
-            .symbol()
-            .starts_with('☑'));
+    #[test]
+    fn checkbox_disabled() {
+        let checkbox = Checkbox::new("Test", true)
+            .style(Style::default().bg(Color::Blue))
+            .disabled(true);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 6, 1));
+        (&checkbox).render(buffer.area, &mut buffer);
+
+        let mut expected = Buffer::with_lines(["☑ Test"]);
+        expected.set_style(expected.area, Style::default().bg(Color::Blue));
+        let disabled = Style::default()
+            .fg(Color::DarkGray)
+            .add_modifier(Modifier::DIM);
+        expected.set_style(Rect::new(0, 0, 1, 1), disabled);
+        expected.set_style(Rect::new(2, 0, 4, 1), disabled);
+        assert_eq!(buffer, expected);
+        assert!(!checkbox.hit_test(buffer.area, 0, 0));
     }
 
     #[test]
-    fn checkbox_render_empty_area() {
-        let checkbox = Checkbox::new("Test", true);
-        let mut buffer = Buffer::empty(Rect::new(0, 0, 0, 0));
+    fn checkbox_disabled_style() {
+        let checkbox = Checkbox::new("Test", false)
+            .label_style(Style::default().fg(Color::Red).add_modifier(Modifier::BOLD))
+            .disabled(true)
+            .disabled_style(Style::default().fg(Color::Gray));
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 6, 1));
+        checkbox.render(buffer.area, &mut buffer);
 
-        // Should not panic
+        assert_eq!(buffer[(0, 0)].fg, Color::Gray);
+        assert_eq!(buffer[(2, 0)].fg, Color::Gray);
+        assert!(buffer[(2, 0)].modifier.contains(Modifier::BOLD));
+    }
+
+    #[test]
+    fn checkbox_disabled_color_scheme() {
+        let checkbox = Checkbox::new("Test", false)
+            .color_scheme(ColorScheme::light())
+            .disabled(true);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 6, 1));
         checkbox.render(buffer.area, &mut buffer);
+
+        assert_eq!(buffer[(0, 0)].fg, ColorScheme::light().disabled_fg);
+        assert_eq!(buffer[(2, 0)].fg, ColorScheme::light().disabled_fg);
     }
 
+    #[cfg(feature = "serde")]
     #[test]
-    fn checkbox_render_with_block() {
-        let checkbox = Checkbox::new("Test", true).block(Block::bordered());
-        let mut buffer = Buffer::empty(Rect::new(0, 0, 12, 3));
+    fn checkbox_serde_round_trip() {
+        let checkbox = Checkbox::new("Enable feature", true)
+            .checked_symbol("[x]")
+            .unchecked_symbol("[ ]")
+            .label_position(LabelPosition::Left)
+            .horizontal_alignment(HorizontalAlignment::Center)
+            .vertical_alignment(VerticalAlignment::Bottom)
+            .min_width(10)
+            .max_width(30)
+            .label_style(Style::default().fg(Color::Green));
 
-        // Should not panic
-        checkbox.render(buffer.area, &mut buffer);
+        let json = serde_json::to_string(&checkbox).unwrap();
+        let restored: Checkbox = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored, checkbox);
     }
 
     #[test]
-    fn checkbox_render_with_custom_symbols() {
-        let checkbox = Checkbox::new("Test", true)
-            .checked_symbol("[X]")
-            .unchecked_symbol("[ ]");
+    fn checkbox_wrapped_cjk_label() {
+        let checkbox = Checkbox::new("日本語 テキスト", true).wrap_label(true);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 8, 2));
+        checkbox.render(buffer.area, &mut buffer);
 
-        let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 1));
+        assert_eq!(buffer, Buffer::with_lines(["☑ 日本語", "  テキス"]));
+    }
+
+    #[test]
+    fn checkbox_truncate_keep_suffix_wide_glyphs() {
+        let checkbox = Checkbox::new("資料_最終版.pdf", false).truncate_keep_suffix(4);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 12, 1));
         checkbox.render(buffer.area, &mut buffer);
 
-        assert!(buffer
-            .cell(buffer.area.as_position())
-            .unwrap()
-            .symbol()
-            .starts_with('['));
+        assert_eq!(buffer, Buffer::with_lines(["☐ 資料_….pdf"]));
     }
 
     #[test]
-    fn checkbox_with_styled_label() {
-        let checkbox = Checkbox::new("Test".blue(), true);
-        assert_eq!(checkbox.label.spans[0].style.fg, Some(Color::Blue));
+    fn checkbox_empty_label_left() {
+        let checkbox = Checkbox::new("", true).label_position(LabelPosition::Left);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 3, 1));
+        (&checkbox).render(buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(["☑  "]));
+
+        let checkbox = checkbox.horizontal_alignment(HorizontalAlignment::Right);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 3, 1));
+        checkbox.render(buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(["  ☑"]));
     }
 
     #[test]
-    fn checkbox_complex_styling() {
-        let checkbox = Checkbox::new("Feature", true)
-            .style(Style::default().fg(Color::White))
-            .checkbox_style(
-                Style::default()
-                    .fg(Color::Green)
-                    .add_modifier(Modifier::BOLD),
-            )
-            .label_style(Style::default().fg(Color::Gray));
+    fn checkbox_label_text() {
+        let checkbox = Checkbox::new("", true).label_text("Title\nFirst hint\nSecond");
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 12, 3));
+        (&checkbox).render(buffer.area, &mut buffer);
+        assert_eq!(
+            buffer,
+            Buffer::with_lines(["  Title     ", "☑ First hint", "  Second    "])
+        );
 
-        assert_eq!(checkbox.style.fg, Some(Color::White));
-        assert_eq!(checkbox.checkbox_style.fg, Some(Color::Green));
-        assert_eq!(checkbox.label_style.fg, Some(Color::Gray));
+        let checkbox = checkbox.label_position(LabelPosition::Left);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 12, 3));
+        (&checkbox).render(buffer.area, &mut buffer);
+        assert_eq!(
+            buffer,
+            Buffer::with_lines(["Title       ", "First hint ☑", "Second      "])
+        );
+        assert_eq!(checkbox.measure(20), (12, 3));
     }
 
     #[test]
-    fn checkbox_emoji_symbols() {
-        let checkbox = Checkbox::new("Test", true)
-            .checked_symbol("✅ ")
-            .unchecked_symbol("⬜ ");
+    fn checkbox_label_text_wrapped_lines() {
+        let checkbox = Checkbox::default()
+            .label_text("One two\nThree")
+            .wrap_label(true)
+            .label_position(LabelPosition::Bottom);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 5, 4));
+        checkbox.render(buffer.area, &mut buffer);
 
-        assert_eq!(checkbox.checked_symbol, "✅ ");
-        assert_eq!(checkbox.unchecked_symbol, "⬜ ");
+        assert_eq!(
+            buffer,
+            Buffer::with_lines(["☐    ", "One  ", "two  ", "Three"])
+        );
     }
 
     #[test]
-    fn checkbox_unicode_symbols() {
-        let checkbox = Checkbox::new("Test", false)
-            .checked_symbol("● ")
-            .unchecked_symbol("○ ");
+    fn checkbox_width_bounds() {
+        let checkbox = Checkbox::new("Enable dark mode", false).wrap_label(true);
+        let (min, desired) = checkbox.width_bounds();
+        assert_eq!((min, desired), (8, 18));
+        assert_eq!(checkbox.measure(min), (8, 3));
 
-        assert_eq!(checkbox.checked_symbol, "● ");
-        assert_eq!(checkbox.unchecked_symbol, "○ ");
+        let checkbox = checkbox.wrap_label(false);
+        assert_eq!(checkbox.width_bounds(), (18, 18));
+
+        let checkbox = Checkbox::new("Enable dark mode", false)
+            .wrap_label(true)
+            .label_position(LabelPosition::Top)
+            .block(Block::bordered());
+        let (min, desired) = checkbox.width_bounds();
+        assert!(min <= desired);
+        assert_eq!((min, desired), (8, 18));
     }
 
     #[test]
-    fn checkbox_arrow_symbols() {
+    fn checkbox_focus_style_wins() {
         let checkbox = Checkbox::new("Test", true)
-            .checked_symbol("▶ ")
-            .unchecked_symbol("▷ ");
+            .checkbox_style(Style::default().fg(Color::Green))
+            .label_style(Style::default().fg(Color::White))
+            .disabled(true)
+            .focus_style(Style::default().fg(Color::Yellow).bg(Color::Blue));
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 6, 1));
+        (&checkbox).render(buffer.area, &mut buffer);
+        assert_eq!(buffer[(0, 0)].fg, Color::DarkGray);
 
-        assert_eq!(checkbox.checked_symbol, "▶ ");
-        assert_eq!(checkbox.unchecked_symbol, "▷ ");
+        let checkbox = checkbox.focused(true);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 6, 1));
+        checkbox.render(buffer.area, &mut buffer);
+        for x in [0, 2, 5] {
+            assert_eq!(buffer[(x, 0)].fg, Color::Yellow);
+            assert_eq!(buffer[(x, 0)].bg, Color::Blue);
+            assert!(buffer[(x, 0)].modifier.contains(Modifier::DIM));
+        }
     }
 
     #[test]
-    fn checkbox_parenthesis_symbols() {
-        let checkbox = Checkbox::new("Test", false)
-            .checked_symbol("(X)")
-            .unchecked_symbol("(O)");
+    fn checkbox_focus_style_default_underlined() {
+        let checkbox = Checkbox::new("Test", false).focused(true);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 7, 1));
+        checkbox.render(buffer.area, &mut buffer);
 
-        assert_eq!(checkbox.checked_symbol, "(X)");
-        assert_eq!(checkbox.unchecked_symbol, "(O)");
+        let mut expected = Buffer::with_lines(["☐ Test "]);
+        expected.set_style(Rect::new(0, 0, 1, 1), Modifier::UNDERLINED);
+        expected.set_style(Rect::new(2, 0, 4, 1), Modifier::UNDERLINED);
+        assert_eq!(buffer, expected);
     }
 
     #[test]
-    fn checkbox_minus_symbols() {
-        let checkbox = Checkbox::new("Test", false)
-            .checked_symbol("[+]")
-            .unchecked_symbol("[-]");
+    fn checkbox_check_animation() {
+        let symbol = |frame: u16| {
+            Checkbox::new("Test", true)
+                .check_animation(3)
+                .animation_frame(frame)
+                .current_symbol()
+                .to_string()
+        };
 
-        assert_eq!(checkbox.checked_symbol, "[+]");
-        assert_eq!(checkbox.unchecked_symbol, "[-]");
+        assert_eq!(symbol(0), " ");
+        assert_eq!(symbol(1), "░");
+        assert_eq!(symbol(2), "▒");
+        assert_eq!(symbol(3), "☑");
+        assert_eq!(symbol(10), "☑");
     }
 
     #[test]
-    fn checkbox_predefined_minus_symbol() {
-        use crate::symbols;
-        let checkbox = Checkbox::new("Test", false).unchecked_symbol(symbols::UNCHECKED_MINUS);
+    fn checkbox_check_animation_fills_left_to_right() {
+        let checkbox = Checkbox::new("Test", true)
+            .checked_symbol("[X]")
+            .check_animation(6);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 8, 1));
+        checkbox
+            .clone()
+            .animation_frame(3)
+            .render(buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(["▓▒  Test"]));
 
-        assert_eq!(checkbox.unchecked_symbol, "[-]");
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 8, 1));
+        checkbox
+            .clone()
+            .animation_frame(6)
+            .render(buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(["[X] Test"]));
+
+        // Without a frame, the checkbox renders its final state
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 8, 1));
+        checkbox.render(buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(["[X] Test"]));
     }
 
     #[test]
-    fn checkbox_predefined_parenthesis_symbols() {
-        use crate::symbols;
-        let checkbox = Checkbox::new("Test", true)
-            .checked_symbol(symbols::CHECKED_PARENTHESIS_X)
-            .unchecked_symbol(symbols::UNCHECKED_PARENTHESIS_O);
+    fn checkbox_symbol_set() {
+        let checkbox = Checkbox::new("Test", false).symbols(symbols::SymbolSet::ascii());
+        assert_eq!(checkbox.checked_symbol, "[X]");
+        assert_eq!(checkbox.unchecked_symbol, "[ ]");
+        assert_eq!(checkbox.indeterminate_symbol, "[-]");
 
+        let checkbox = checkbox.symbols(symbols::SymbolSet::parenthesis());
         assert_eq!(checkbox.checked_symbol, "(X)");
         assert_eq!(checkbox.unchecked_symbol, "(O)");
+        assert_eq!(checkbox.indeterminate_symbol, "[-]");
+
+        let checkbox = checkbox.symbols(symbols::SymbolSet::default());
+        assert_eq!(checkbox, Checkbox::new("Test", false));
     }
 
     #[test]
-    fn checkbox_render_emoji() {
-        let checkbox = Checkbox::new("Emoji", true)
-            .checked_symbol("✅ ")
-            .unchecked_symbol("⬜ ");
-
-        let mut buffer = Buffer::empty(Rect::new(0, 0, 15, 1));
+    fn checkbox_indeterminate() {
+        let checkbox = Checkbox::new("Test", true).indeterminate(true);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 6, 1));
         checkbox.render(buffer.area, &mut buffer);
 
-        // Should render without panic
-        assert!(buffer.area.area() > 0);
+        assert_eq!(buffer, Buffer::with_lines(["▣ Test"]));
     }
 
     #[test]
-    fn checkbox_label_style_overrides() {
-        let checkbox = Checkbox::new("Test", true)
-            .style(Style::default().fg(Color::White))
-            .label_style(Style::default().fg(Color::Blue));
+    fn checkbox_inline_icon() {
+        let icon = Span::styled("*", Style::default().fg(Color::Blue));
+        let checkbox = Checkbox::new("Save {icon} changes", false)
+            .label_style(Style::default().add_modifier(Modifier::BOLD))
+            .with_inline_icon("{icon}", icon);
+        assert_eq!(
+            checkbox.styled_label(checkbox.content_styles().1)[0],
+            Line::from(vec![
+                Span::styled("Save ", Style::default().add_modifier(Modifier::BOLD)),
+                Span::styled(
+                    "*",
+                    Style::default()
+                        .fg(Color::Blue)
+                        .add_modifier(Modifier::BOLD)
+                ),
+                Span::styled(" changes", Style::default().add_modifier(Modifier::BOLD)),
+            ])
+        );
 
-        assert_eq!(checkbox.style.fg, Some(Color::White));
-        assert_eq!(checkbox.label_style.fg, Some(Color::Blue));
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 16, 1));
+        checkbox.render(buffer.area, &mut buffer);
+        assert_eq!(buffer[(7, 0)].symbol(), "*");
+        assert_eq!(buffer[(7, 0)].fg, Color::Blue);
+        assert_eq!(buffer[(8, 0)].symbol(), " ");
+        assert_eq!(buffer[(9, 0)].symbol(), "c");
     }
 }
@@ -0,0 +1,27 @@
+//! Serde support for the [`Text`] label of a [`Checkbox`], which ratatui does not serialize
+//!
+//! The label is stored as its plain text with one `\n` between lines, so the styles of individual
+//! spans are not persisted.
+//!
+//! [`Checkbox`]: crate::Checkbox
+
+use ratatui::text::{Line, Text};
+use serde::{Deserialize, Deserializer, Serializer};
+
+pub fn serialize<S>(label: &Text<'_>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.collect_str(label)
+}
+
+pub fn deserialize<'de, 'a, D>(deserializer: D) -> Result<Text<'a>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let label = String::deserialize(deserializer)?;
+    if label.is_empty() {
+        return Ok(Text::from(Line::default()));
+    }
+    Ok(Text::from(label))
+}
@@ -0,0 +1,264 @@
+//! State for rendering a [`Checkbox`] as a [`StatefulWidget`]
+//!
+//! [`Checkbox`]: crate::Checkbox
+
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::widgets::{StatefulWidget, Widget};
+
+use crate::Checkbox;
+
+/// State of a [`Checkbox`] rendered as a [`StatefulWidget`].
+///
+/// The state holds whether the checkbox is checked and focused, so an application can keep it in
+/// its model and mutate it from the event loop instead of rebuilding the widget from `bool`s. When
+/// rendered with a state, the state's values take precedence over [`Checkbox::checked`] and
+/// [`Checkbox::focused`].
+///
+/// Changes of the checked state are recorded until [`CheckboxState::take_changed`] is called, so a
+/// render loop can react to them, such as marking a form dirty, without diffing states.
+///
+/// # Examples
+///
+/// ```
+/// use ratatui::buffer::Buffer;
+/// use ratatui::layout::Rect;
+/// use ratatui::widgets::StatefulWidget;
+/// use tui_checkbox::{Checkbox, CheckboxState};
+///
+/// let mut state = CheckboxState::new(false);
+/// state.toggle();
+///
+/// let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 1));
+/// Checkbox::new("Option", false).render(buffer.area, &mut buffer, &mut state);
+/// ```
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash)]
+pub struct CheckboxState {
+    /// Whether the checkbox is checked
+    checked: bool,
+    /// Whether the checkbox has keyboard focus
+    focused: bool,
+    /// Whether the checked state changed since the last [`CheckboxState::take_changed`]
+    changed: bool,
+}
+
+impl CheckboxState {
+    /// Creates a new unfocused state with the given checked state.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tui_checkbox::CheckboxState;
+    ///
+    /// let state = CheckboxState::new(true);
+    /// assert!(state.is_checked());
+    /// ```
+    #[must_use]
+    pub const fn new(checked: bool) -> Self {
+        Self {
+            checked,
+            focused: false,
+            changed: false,
+        }
+    }
+
+    /// Sets the focused state.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tui_checkbox::CheckboxState;
+    ///
+    /// let state = CheckboxState::new(false).with_focused(true);
+    /// assert!(state.is_focused());
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn with_focused(mut self, focused: bool) -> Self {
+        self.focused = focused;
+        self
+    }
+
+    /// Returns whether the checkbox is checked.
+    #[must_use]
+    pub const fn is_checked(&self) -> bool {
+        self.checked
+    }
+
+    /// Returns whether the checkbox has keyboard focus.
+    #[must_use]
+    pub const fn is_focused(&self) -> bool {
+        self.focused
+    }
+
+    /// Sets the checked state, marking the state as changed if it differs from the current one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tui_checkbox::CheckboxState;
+    ///
+    /// let mut state = CheckboxState::default();
+    /// state.set_checked(true);
+    /// assert!(state.is_checked());
+    /// ```
+    pub fn set_checked(&mut self, checked: bool) {
+        self.changed |= self.checked != checked;
+        self.checked = checked;
+    }
+
+    /// Sets the focused state.
+    pub fn set_focused(&mut self, focused: bool) {
+        self.focused = focused;
+    }
+
+    /// Flips the checked state and returns the new one, marking the state as changed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tui_checkbox::CheckboxState;
+    ///
+    /// let mut state = CheckboxState::new(false);
+    /// assert!(state.toggle());
+    /// assert!(state.is_checked());
+    /// ```
+    pub fn toggle(&mut self) -> bool {
+        self.set_checked(!self.checked);
+        self.checked
+    }
+
+    /// Returns whether the checked state changed since the last call, and clears the flag.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tui_checkbox::CheckboxState;
+    ///
+    /// let mut state = CheckboxState::new(false);
+    /// state.toggle();
+    /// assert!(state.take_changed());
+    /// assert!(!state.take_changed());
+    /// ```
+    pub fn take_changed(&mut self) -> bool {
+        std::mem::take(&mut self.changed)
+    }
+
+    /// Returns the direction of the last change of the checked state, `Some(true)` when it was
+    /// checked and `Some(false)` when it was unchecked, or `None` when it didn't change.
+    ///
+    /// This lets an application react to checking and unchecking differently, such as playing a
+    /// different sound for each. Like [`CheckboxState::take_changed`], it clears the flag.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tui_checkbox::CheckboxState;
+    ///
+    /// let mut state = CheckboxState::new(false);
+    /// state.toggle();
+    /// assert_eq!(state.take_transition(), Some(true));
+    /// assert_eq!(state.take_transition(), None);
+    /// ```
+    pub fn take_transition(&mut self) -> Option<bool> {
+        self.take_changed().then_some(self.checked)
+    }
+}
+
+impl StatefulWidget for Checkbox<'_> {
+    type State = CheckboxState;
+
+    fn render(mut self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        self.checked = state.checked;
+        self.focused = state.focused;
+        Widget::render(&self, area, buf);
+    }
+}
+
+impl StatefulWidget for &Checkbox<'_> {
+    type State = CheckboxState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        StatefulWidget::render(self.clone(), area, buf, state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn state_toggle() {
+        let mut state = CheckboxState::new(false);
+        state.toggle();
+        assert!(state.is_checked());
+        state.toggle();
+        assert!(!state.is_checked());
+    }
+
+    #[test]
+    fn state_set_checked() {
+        let mut state = CheckboxState::default();
+        state.set_checked(true);
+        assert!(state.is_checked());
+        state.set_checked(false);
+        assert!(!state.is_checked());
+    }
+
+    #[test]
+    fn state_toggle_returns_new_state() {
+        let mut state = CheckboxState::new(false);
+        assert!(state.toggle());
+        assert!(!state.toggle());
+    }
+
+    #[test]
+    fn state_toggle_sets_changed() {
+        let mut state = CheckboxState::new(false);
+        assert!(!state.take_changed());
+        state.toggle();
+        assert!(state.take_changed());
+        assert!(!state.take_changed());
+    }
+
+    #[test]
+    fn state_set_checked_changed_only_on_difference() {
+        let mut state = CheckboxState::new(true);
+        state.set_checked(true);
+        assert!(!state.take_changed());
+        state.set_checked(false);
+        assert!(state.take_changed());
+
+        state.set_focused(true);
+        assert!(!state.take_changed());
+    }
+
+    #[test]
+    fn state_take_transition() {
+        let mut state = CheckboxState::new(false);
+        assert_eq!(state.take_transition(), None);
+
+        state.set_checked(true);
+        assert_eq!(state.take_transition(), Some(true));
+        assert_eq!(state.take_transition(), None);
+
+        state.toggle();
+        assert_eq!(state.take_transition(), Some(false));
+        assert!(!state.take_changed());
+
+        state.set_checked(false);
+        assert_eq!(state.take_transition(), None);
+    }
+
+    #[test]
+    fn render_reflects_state() {
+        let checkbox = Checkbox::new("Test", false);
+        let mut state = CheckboxState::new(true);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 6, 1));
+        StatefulWidget::render(&checkbox, buffer.area, &mut buffer, &mut state);
+        assert_eq!(buffer, Buffer::with_lines(["☑ Test"]));
+
+        state.toggle();
+        StatefulWidget::render(checkbox, buffer.area, &mut buffer, &mut state);
+        assert_eq!(buffer, Buffer::with_lines(["☐ Test"]));
+    }
+}
@@ -10,6 +10,18 @@ pub const CHECKED: &str = "☑";
 /// Unchecked checkbox symbol (☐)
 pub const UNCHECKED: &str = "☐";
 
+/// Indeterminate checkbox symbol, for a partially selected group (▣)
+pub const INDETERMINATE: &str = "▣";
+
+/// Pending checkbox symbol, for a change that is still being applied (…)
+pub const PENDING: &str = "…";
+
+/// Checked radio button symbol (◉)
+pub const RADIO_CHECKED: &str = "◉";
+
+/// Unchecked radio button symbol (○)
+pub const RADIO_UNCHECKED: &str = "○";
+
 /// Alternative checked checkbox symbol with X
 pub const CHECKED_X: &str = "[X]";
 
@@ -68,3 +80,276 @@ pub const CHECKED_PARENTHESIS_X: &str = "(X)";
 ///     .unchecked_symbol(symbols::UNCHECKED_PARENTHESIS_O);
 /// ```
 pub const UNCHECKED_PARENTHESIS_O: &str = "(O)";
+
+/// GitHub task list checked symbol (`[x]`)
+///
+/// # Examples
+///
+/// ```
+/// use tui_checkbox::{Checkbox, symbols};
+///
+/// let checkbox = Checkbox::new("Task", true)
+///     .checked_symbol(symbols::CHECKED_TASK)
+///     .unchecked_symbol(symbols::UNCHECKED_SPACE);
+/// ```
+pub const CHECKED_TASK: &str = "[x]";
+
+/// Checked ballot box symbol (☒)
+///
+/// # Examples
+///
+/// ```
+/// use tui_checkbox::{Checkbox, symbols};
+///
+/// let checkbox = Checkbox::new("Task", true)
+///     .checked_symbol(symbols::CHECKED_BALLOT)
+///     .unchecked_symbol(symbols::UNCHECKED_BALLOT);
+/// ```
+pub const CHECKED_BALLOT: &str = "☒";
+
+/// Unchecked ballot box symbol (☐)
+///
+/// # Examples
+///
+/// ```
+/// use tui_checkbox::{Checkbox, symbols};
+///
+/// let checkbox = Checkbox::new("Task", false)
+///     .checked_symbol(symbols::CHECKED_BALLOT)
+///     .unchecked_symbol(symbols::UNCHECKED_BALLOT);
+/// ```
+pub const UNCHECKED_BALLOT: &str = "☐";
+
+/// Heavy check mark symbol (✔)
+///
+/// # Examples
+///
+/// ```
+/// use tui_checkbox::{Checkbox, symbols};
+///
+/// let checkbox = Checkbox::new("Task", true)
+///     .checked_symbol(symbols::CHECKED_HEAVY)
+///     .unchecked_symbol(" ");
+/// ```
+pub const CHECKED_HEAVY: &str = "✔";
+
+/// Toggle switch symbol when on (◉)
+///
+/// # Examples
+///
+/// ```
+/// use tui_checkbox::{Checkbox, symbols};
+///
+/// let checkbox = Checkbox::new("Wi-Fi", true)
+///     .checked_symbol(symbols::TOGGLE_ON)
+///     .unchecked_symbol(symbols::TOGGLE_OFF);
+/// ```
+pub const TOGGLE_ON: &str = "◉";
+
+/// Toggle switch symbol when off (○)
+///
+/// # Examples
+///
+/// ```
+/// use tui_checkbox::{Checkbox, symbols};
+///
+/// let checkbox = Checkbox::new("Wi-Fi", false)
+///     .checked_symbol(symbols::TOGGLE_ON)
+///     .unchecked_symbol(symbols::TOGGLE_OFF);
+/// ```
+pub const TOGGLE_OFF: &str = "○";
+
+/// Emoji checked checkbox symbol (✅), two cells wide in most terminals
+///
+/// # Examples
+///
+/// ```
+/// use tui_checkbox::{Checkbox, symbols};
+///
+/// let checkbox = Checkbox::new("Ship it", true)
+///     .checked_symbol(symbols::CHECKED_EMOJI)
+///     .unchecked_symbol(symbols::UNCHECKED_EMOJI);
+/// ```
+pub const CHECKED_EMOJI: &str = "✅";
+
+/// Emoji unchecked checkbox symbol (⬜), two cells wide in most terminals
+///
+/// # Examples
+///
+/// ```
+/// use tui_checkbox::{Checkbox, symbols};
+///
+/// let checkbox = Checkbox::new("Ship it", false)
+///     .checked_symbol(symbols::CHECKED_EMOJI)
+///     .unchecked_symbol(symbols::UNCHECKED_EMOJI);
+/// ```
+pub const UNCHECKED_EMOJI: &str = "⬜";
+
+/// Nerd Font checked checkbox symbol (`nf-md-checkbox_marked`, U+F0132)
+///
+/// Requires the `nerd-fonts` feature and a patched [Nerd Font](https://www.nerdfonts.com).
+///
+/// # Examples
+///
+/// ```
+/// use tui_checkbox::{Checkbox, symbols};
+///
+/// let checkbox = Checkbox::new("Task", true)
+///     .checked_symbol(symbols::NERD_CHECKED)
+///     .unchecked_symbol(symbols::NERD_UNCHECKED);
+/// ```
+#[cfg(feature = "nerd-fonts")]
+pub const NERD_CHECKED: &str = "\u{F0132}";
+
+/// Nerd Font unchecked checkbox symbol (`nf-md-checkbox_blank_outline`, U+F0131)
+///
+/// Requires the `nerd-fonts` feature and a patched [Nerd Font](https://www.nerdfonts.com).
+///
+/// # Examples
+///
+/// ```
+/// use tui_checkbox::{Checkbox, symbols};
+///
+/// let checkbox = Checkbox::new("Task", false)
+///     .checked_symbol(symbols::NERD_CHECKED)
+///     .unchecked_symbol(symbols::NERD_UNCHECKED);
+/// ```
+#[cfg(feature = "nerd-fonts")]
+pub const NERD_UNCHECKED: &str = "\u{F0131}";
+
+/// Nerd Font indeterminate checkbox symbol (`nf-md-checkbox_intermediate`, U+F0856)
+///
+/// Requires the `nerd-fonts` feature and a patched [Nerd Font](https://www.nerdfonts.com).
+///
+/// # Examples
+///
+/// ```
+/// use tui_checkbox::{Checkbox, symbols};
+///
+/// let checkbox = Checkbox::new("Group", false)
+///     .indeterminate(true)
+///     .indeterminate_symbol(symbols::NERD_INDETERMINATE);
+/// ```
+#[cfg(feature = "nerd-fonts")]
+pub const NERD_INDETERMINATE: &str = "\u{F0856}";
+
+/// Tree guide drawn by [`Checkbox::tree_guides`](crate::Checkbox::tree_guides) for each ancestor
+/// level of an indented checkbox (│)
+pub const TREE_PIPE: &str = "│  ";
+
+/// Tree guide drawn by [`Checkbox::tree_guides`](crate::Checkbox::tree_guides) right before an
+/// indented checkbox (├─)
+pub const TREE_BRANCH: &str = "├─ ";
+
+/// Marker in the label replaced by the symbol when
+/// [`Checkbox::inline_symbol`](crate::Checkbox::inline_symbol) is enabled
+pub const INLINE_MARKER: &str = "{}";
+
+/// Left cap of the pill highlight drawn by [`Checkbox::pill`](crate::Checkbox::pill) (▐)
+pub const PILL_LEFT: &str = "▐";
+
+/// Right cap of the pill highlight drawn by [`Checkbox::pill`](crate::Checkbox::pill) (▌)
+pub const PILL_RIGHT: &str = "▌";
+
+/// Fill levels used by [`Checkbox::partial`](crate::Checkbox::partial), from one eighth (▁) to
+/// seven eighths (▇)
+pub const PARTIAL_FILL: [&str; 7] = ["▁", "▂", "▃", "▄", "▅", "▆", "▇"];
+
+/// Shades used by [`Checkbox::check_animation`](crate::Checkbox::check_animation) to fill each
+/// cell of the symbol, from light (░) to dark (▓)
+pub const SHADES: [&str; 3] = ["░", "▒", "▓"];
+
+/// Top-left focus corner drawn by [`Checkbox::focus_corners`](crate::Checkbox::focus_corners) (⌜)
+pub const FOCUS_TOP_LEFT: &str = "⌜";
+
+/// Top-right focus corner drawn by [`Checkbox::focus_corners`](crate::Checkbox::focus_corners) (⌝)
+pub const FOCUS_TOP_RIGHT: &str = "⌝";
+
+/// Bottom-left focus corner drawn by [`Checkbox::focus_corners`](crate::Checkbox::focus_corners)
+/// (⌞)
+pub const FOCUS_BOTTOM_LEFT: &str = "⌞";
+
+/// Bottom-right focus corner drawn by [`Checkbox::focus_corners`](crate::Checkbox::focus_corners)
+/// (⌟)
+pub const FOCUS_BOTTOM_RIGHT: &str = "⌟";
+
+/// Indicator drawn by [`Checkbox::clip_indicator`](crate::Checkbox::clip_indicator) when the
+/// minimum width cannot be honored (›)
+pub const CLIP_INDICATOR: &str = "›";
+
+/// A set of symbols for each state of a checkbox, applied at once with
+/// [`Checkbox::symbols`](crate::Checkbox::symbols).
+///
+/// # Examples
+///
+/// ```
+/// use tui_checkbox::symbols::SymbolSet;
+/// use tui_checkbox::Checkbox;
+///
+/// const THEME: SymbolSet = SymbolSet {
+///     checked: "✔",
+///     unchecked: "·",
+///     indeterminate: None,
+/// };
+///
+/// let checkbox = Checkbox::new("Task", true).symbols(THEME);
+/// let ascii = Checkbox::new("Task", false).symbols(SymbolSet::ascii());
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct SymbolSet<'a> {
+    /// Symbol shown when checked
+    pub checked: &'a str,
+    /// Symbol shown when unchecked
+    pub unchecked: &'a str,
+    /// Symbol shown when indeterminate, if the set has one
+    pub indeterminate: Option<&'a str>,
+}
+
+impl SymbolSet<'static> {
+    /// Returns the default Unicode symbols (☑, ☐ and ▣).
+    #[must_use]
+    pub const fn unicode() -> Self {
+        Self {
+            checked: CHECKED,
+            unchecked: UNCHECKED,
+            indeterminate: Some(INDETERMINATE),
+        }
+    }
+
+    /// Returns ASCII symbols in brackets (`[X]`, `[ ]` and `[-]`).
+    #[must_use]
+    pub const fn ascii() -> Self {
+        Self {
+            checked: CHECKED_X,
+            unchecked: UNCHECKED_SPACE,
+            indeterminate: Some(UNCHECKED_MINUS),
+        }
+    }
+
+    /// Returns emoji symbols (✅ and ⬜), without an indeterminate symbol.
+    #[must_use]
+    pub const fn emoji() -> Self {
+        Self {
+            checked: CHECKED_EMOJI,
+            unchecked: UNCHECKED_EMOJI,
+            indeterminate: None,
+        }
+    }
+
+    /// Returns ASCII symbols in parentheses ((X) and (O)), without an indeterminate symbol.
+    #[must_use]
+    pub const fn parenthesis() -> Self {
+        Self {
+            checked: CHECKED_PARENTHESIS_X,
+            unchecked: UNCHECKED_PARENTHESIS_O,
+            indeterminate: None,
+        }
+    }
+}
+
+impl Default for SymbolSet<'static> {
+    /// Returns the default Unicode symbols.
+    fn default() -> Self {
+        Self::unicode()
+    }
+}
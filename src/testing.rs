@@ -0,0 +1,85 @@
+//! Test helpers for asserting how a [`Checkbox`] renders
+//!
+//! This module is only available with the `testing` feature.
+//!
+//! [`Checkbox`]: crate::Checkbox
+
+use std::fmt::Write;
+
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::widgets::Widget;
+
+use crate::{buffer_rows, Checkbox};
+
+/// Renders `checkbox` into `area` and asserts that each row matches `expected`.
+///
+/// Rows are compared by their symbols only, ignoring styles and trailing whitespace. Missing
+/// expected rows are treated as empty.
+///
+/// # Panics
+///
+/// Panics with a row-by-row diff when the rendered rows don't match `expected`.
+///
+/// # Examples
+///
+/// ```
+/// use ratatui::layout::Rect;
+/// use tui_checkbox::testing::assert_renders;
+/// use tui_checkbox::Checkbox;
+///
+/// assert_renders(&Checkbox::new("Test", true), Rect::new(0, 0, 10, 1), &["☑ Test"]);
+/// ```
+#[track_caller]
+pub fn assert_renders(checkbox: &Checkbox<'_>, area: Rect, expected: &[&str]) {
+    let mut buffer = Buffer::empty(area);
+    checkbox.render(area, &mut buffer);
+    let actual = buffer_rows(&buffer);
+
+    if actual
+        .iter()
+        .enumerate()
+        .all(|(i, row)| *row == expected.get(i).map_or("", |e| e.trim_end()))
+        && expected.len() <= actual.len()
+    {
+        return;
+    }
+
+    let mut message = String::from("checkbox render mismatch (- expected, + actual):\n");
+    for i in 0..actual.len().max(expected.len()) {
+        let expected_row = expected.get(i).map_or("", |e| e.trim_end());
+        let actual_row = actual.get(i).map_or("", String::as_str);
+        if expected_row == actual_row {
+            let _ = writeln!(message, "  {i:>3} |{actual_row}|");
+        } else {
+            let _ = writeln!(message, "- {i:>3} |{expected_row}|");
+            let _ = writeln!(message, "+ {i:>3} |{actual_row}|");
+        }
+    }
+    panic!("{message}");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::LabelPosition;
+
+    #[test]
+    fn assert_renders_matches() {
+        let checkbox = Checkbox::new("Test", true).label_position(LabelPosition::Bottom);
+        assert_renders(&checkbox, Rect::new(0, 0, 6, 3), &["☑", "Test", ""]);
+    }
+
+    #[test]
+    fn assert_renders_wide_symbol() {
+        let checkbox = Checkbox::new("Test", true).checked_symbol("✅");
+        assert_renders(&checkbox, Rect::new(0, 0, 10, 1), &["✅ Test"]);
+    }
+
+    #[test]
+    #[should_panic(expected = "- ")]
+    fn assert_renders_mismatch() {
+        let checkbox = Checkbox::new("Test", false);
+        assert_renders(&checkbox, Rect::new(0, 0, 10, 1), &["☑ Test"]);
+    }
+}